@@ -0,0 +1,15 @@
+use crate::app_state::skill::Skill;
+
+/// A player action that can be applied to a monster without any I/O.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Feed,
+    Play,
+    Pet,
+    Bath,
+    Medicine,
+    ToggleSleep,
+    Train(Skill),
+    /// Do nothing; useful for extending a simulated schedule.
+    Wait,
+}
@@ -0,0 +1,50 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alert {
+    Dead,
+    /// Died of old age rather than neglect.
+    PassedAway,
+    /// Left starving across several updates.
+    Neglected,
+    Starving,
+    LowHealth,
+    Sick,
+}
+
+impl Alert {
+    pub fn message(&self, name: &str) -> String {
+        match self {
+            Alert::Dead => format!("💀 {} has died!", name),
+            Alert::PassedAway => format!("🕊️ {} passed away peacefully of old age.", name),
+            Alert::Neglected => format!("💔 {} feels neglected.", name),
+            Alert::Starving => format!("🚨 {} is starving! Feed them now!", name),
+            Alert::LowHealth => format!("⚠️ {}'s health is low! Take care of them!", name),
+            Alert::Sick => format!("🤒 {} is sick!", name),
+        }
+    }
+
+    /// Alerts urgent enough to pulse in interactive mode.
+    pub fn is_critical(&self) -> bool {
+        matches!(self, Alert::Neglected | Alert::Starving | Alert::LowHealth)
+    }
+}
+
+/// Coarse condition used for monitoring exit codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HealthBand {
+    /// Nothing needs attention.
+    Healthy,
+    /// Hungry, sad, exhausted, unwell or sick.
+    Warning,
+    /// Starving, critically low health, or dead.
+    Critical,
+}
+
+impl HealthBand {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthBand::Healthy => "healthy",
+            HealthBand::Warning => "warning",
+            HealthBand::Critical => "critical",
+        }
+    }
+}
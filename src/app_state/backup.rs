@@ -0,0 +1,93 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+use crate::app_state::storage;
+
+const BACKUP_DIR: &str = ".monster-backups";
+const MAX_BACKUPS: usize = 5;
+
+/// Moves the state file into the backup directory and prunes all but the newest backups.
+pub fn backup_state_file(state_file: &Path) -> Result<Option<PathBuf>> {
+    if !state_file.exists() {
+        return Ok(None);
+    }
+
+    let dir = backup_dir(state_file);
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create backup directory {}", dir.display()))?;
+
+    let backup = dir.join(format!(
+        "backup-{}.json",
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::rename(state_file, &backup)
+        .with_context(|| format!("Failed to move state file to {}", backup.display()))?;
+
+    for old in list_backups(state_file)?.into_iter().skip(MAX_BACKUPS) {
+        fs::remove_file(&old)
+            .with_context(|| format!("Failed to remove old backup {}", old.display()))?;
+    }
+
+    Ok(Some(backup))
+}
+
+/// Backups for the given state file, newest first.
+pub fn list_backups(state_file: &Path) -> Result<Vec<PathBuf>> {
+    let dir = backup_dir(state_file);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read backup directory {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let is_backup = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("backup-") && name.ends_with(".json"));
+
+        if is_backup {
+            backups.push(path);
+        }
+    }
+
+    backups.sort();
+    backups.reverse();
+
+    Ok(backups)
+}
+
+/// Replaces the state file with a backup, backing up the current state first.
+pub fn restore_backup(state_file: &Path, backup: &Path) -> Result<()> {
+    let content =
+        fs::read(backup).with_context(|| format!("Failed to read backup {}", backup.display()))?;
+
+    backup_state_file(state_file)?;
+
+    storage::write_atomic(state_file, &content)
+        .with_context(|| format!("Failed to restore backup {}", backup.display()))?;
+
+    Ok(())
+}
+
+/// The default save keeps its backups directly in `.monster-backups`; any other save, such as
+/// a pet's, gets a folder there named after its file, so saves never list or prune each
+/// other's backups.
+fn backup_dir(state_file: &Path) -> PathBuf {
+    let dir = state_file
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(BACKUP_DIR);
+
+    match state_file.file_stem() {
+        Some(stem) if !storage::is_default_state_file(state_file) => dir.join(stem),
+        _ => dir,
+    }
+}
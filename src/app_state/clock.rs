@@ -0,0 +1,49 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicI64, Ordering},
+};
+
+use chrono::{DateTime, Duration, Utc};
+
+pub trait Clock: fmt::Debug + Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to, for driving time-dependent logic in tests.
+#[derive(Debug)]
+pub struct MockClock {
+    millis: AtomicI64,
+}
+
+impl MockClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            millis: AtomicI64::new(start.timestamp_millis()),
+        }
+    }
+
+    pub fn set(&self, to: DateTime<Utc>) {
+        self.millis.store(to.timestamp_millis(), Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.millis
+            .fetch_add(by.num_milliseconds(), Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.millis.load(Ordering::SeqCst))
+            .expect("mock clock out of range")
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+use crate::app_state::monster::Monster;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    A,
+    B,
+}
+
+/// One line of a comparison. `higher_is_better` is `None` for stats that are neither good
+/// nor bad, like age.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatRow {
+    pub label: &'static str,
+    pub a: u32,
+    pub b: u32,
+    pub higher_is_better: Option<bool>,
+}
+
+impl StatRow {
+    /// Which side comes out ahead on this stat, if either.
+    pub fn better(&self) -> Option<Side> {
+        let higher_is_better = self.higher_is_better?;
+        if self.a == self.b {
+            return None;
+        }
+
+        if (self.a > self.b) == higher_is_better {
+            Some(Side::A)
+        } else {
+            Some(Side::B)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub rows: Vec<StatRow>,
+    /// The monster in better shape: the living one if only one is alive, otherwise the one
+    /// with more health, then the higher care score. `None` for a tie or two dead monsters.
+    pub healthier: Option<Side>,
+}
+
+pub fn compare(a: &Monster, b: &Monster) -> Comparison {
+    let row = |label, a: u8, b: u8, higher_is_better| StatRow {
+        label,
+        a: a.into(),
+        b: b.into(),
+        higher_is_better: Some(higher_is_better),
+    };
+
+    let rows = vec![
+        row("Health", a.health, b.health, true),
+        row("Hunger", a.hunger, b.hunger, false),
+        row("Happiness", a.happiness, b.happiness, true),
+        row("Energy", a.energy, b.energy, true),
+        row("Bond", a.affection, b.affection, true),
+        row("Care score", a.care_score(), b.care_score(), true),
+        StatRow {
+            label: "Age (hours)",
+            a: a.age,
+            b: b.age,
+            higher_is_better: None,
+        },
+    ];
+
+    let healthier = match (a.is_alive, b.is_alive) {
+        (true, false) => Some(Side::A),
+        (false, true) => Some(Side::B),
+        (false, false) => None,
+        (true, true) => {
+            let score = |monster: &Monster| (monster.health, monster.care_score());
+            match score(a).cmp(&score(b)) {
+                Ordering::Greater => Some(Side::A),
+                Ordering::Less => Some(Side::B),
+                Ordering::Equal => None,
+            }
+        }
+    };
+
+    Comparison { rows, healthier }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_state::species::Species;
+
+    fn monster(health: u8) -> Monster {
+        let mut monster = Monster::new("Testy".to_string(), Species::Blob);
+        monster.health = health;
+        monster
+    }
+
+    fn row<'a>(comparison: &'a Comparison, label: &str) -> &'a StatRow {
+        comparison
+            .rows
+            .iter()
+            .find(|row| row.label == label)
+            .unwrap()
+    }
+
+    #[test]
+    fn more_health_wins() {
+        let comparison = compare(&monster(40), &monster(90));
+
+        assert_eq!(comparison.healthier, Some(Side::B));
+        assert_eq!(row(&comparison, "Health").better(), Some(Side::B));
+    }
+
+    #[test]
+    fn less_hunger_is_better() {
+        let mut a = monster(100);
+        a.hunger = 20;
+        let b = monster(100);
+
+        assert_eq!(row(&compare(&a, &b), "Hunger").better(), Some(Side::A));
+    }
+
+    #[test]
+    fn age_favours_neither_side() {
+        let mut a = monster(100);
+        a.age = 500;
+        let comparison = compare(&a, &monster(100));
+
+        let age = row(&comparison, "Age (hours)");
+        assert_eq!((age.a, age.b), (500, 0));
+        assert_eq!(age.better(), None);
+    }
+
+    #[test]
+    fn the_living_monster_wins_over_a_dead_one() {
+        let mut dead = monster(0);
+        dead.is_alive = false;
+
+        assert_eq!(compare(&dead, &monster(5)).healthier, Some(Side::B));
+        assert_eq!(compare(&monster(5), &dead).healthier, Some(Side::A));
+        assert_eq!(compare(&dead, &dead.clone()).healthier, None);
+    }
+
+    #[test]
+    fn identical_monsters_tie() {
+        let comparison = compare(&monster(70), &monster(70));
+
+        assert_eq!(comparison.healthier, None);
+        assert!(comparison.rows.iter().all(|row| row.better().is_none()));
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::app_state::{
+    monster::DEFAULT_MAX_CATCH_UP_HOURS, storage::SaveFormat, thresholds::Thresholds,
+};
+
+pub const CONFIG_FILE_ENV: &str = "MONS_BOX_CONFIG";
+const DEFAULT_CONFIG_FILE: &str = ".monster-config.json";
+
+/// Player preferences read from the config file. Every field is optional; anything left out
+/// keeps the built-in default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Warn when hunger rises above this.
+    pub hunger_warn: Option<u8>,
+    /// Warn when happiness drops below this.
+    pub happiness_warn: Option<u8>,
+    /// Warn when energy drops below this.
+    pub energy_warn: Option<u8>,
+    /// Warn when health drops below this.
+    pub health_warn: Option<u8>,
+    /// Treat the monster as dirty, with its health risks, below this cleanliness.
+    pub cleanliness_warn: Option<u8>,
+    /// Longest absence, in hours, whose decay is simulated; anything beyond is forgiven.
+    pub max_catch_up_hours: Option<u32>,
+    /// Hours a stat may stay dangerously low (or hunger high) before it starts costing health.
+    pub neglect_grace_hours: Option<u32>,
+    /// Hours a Blob lives before passing away of old age; other species live somewhat
+    /// shorter or longer. Monsters live forever when unset.
+    pub lifespan_hours: Option<u32>,
+    /// Show the hatching animation and the banner before interactive mode starts.
+    pub intro: Option<bool>,
+    /// Replaces the built-in interactive banner.
+    pub banner: Option<String>,
+    /// How to write the state file. When unset, an existing save keeps its own format.
+    pub save_format: Option<SaveFormat>,
+    /// Name unnamed monsters something random instead of the default name.
+    pub random_names: Option<bool>,
+    /// Fail instead of starting over when the save is damaged and no backup can be read.
+    pub strict: Option<bool>,
+}
+
+/// Where the config is read from: `$MONS_BOX_CONFIG` if set, otherwise the default file.
+pub fn config_file() -> PathBuf {
+    env::var_os(CONFIG_FILE_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_FILE))
+}
+
+impl Config {
+    /// Reads and validates `path`, or the default config file when `None`. A missing default
+    /// file is the same as an empty one; a missing explicit path is an error.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => {
+                let path = config_file();
+                if !path.exists() {
+                    return Ok(Self::default());
+                }
+                path
+            }
+        };
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let config: Config = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))?;
+
+        config
+            .validate()
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let fields = [
+            ("hunger_warn", self.hunger_warn),
+            ("happiness_warn", self.happiness_warn),
+            ("energy_warn", self.energy_warn),
+            ("health_warn", self.health_warn),
+            ("cleanliness_warn", self.cleanliness_warn),
+        ];
+
+        for (field, value) in fields {
+            if let Some(value) = value
+                && value > 100
+            {
+                bail!("{} must be between 0 and 100, got {}", field, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Every setting filled in, taking this config's value where set and the default otherwise.
+    /// `banner` and `lifespan_hours` stay `None` unless overridden, meaning the built-in
+    /// banner and no lifespan.
+    pub fn resolved(&self) -> Self {
+        let thresholds = self.thresholds();
+
+        Self {
+            hunger_warn: Some(thresholds.hungry),
+            happiness_warn: Some(thresholds.sad),
+            energy_warn: Some(thresholds.exhausted),
+            health_warn: Some(thresholds.unwell),
+            cleanliness_warn: Some(thresholds.dirty),
+            max_catch_up_hours: Some(
+                self.max_catch_up_hours
+                    .unwrap_or(DEFAULT_MAX_CATCH_UP_HOURS),
+            ),
+            neglect_grace_hours: Some(self.neglect_grace_hours.unwrap_or(0)),
+            lifespan_hours: self.lifespan_hours,
+            intro: Some(self.intro.unwrap_or(true)),
+            banner: self.banner.clone(),
+            save_format: self.save_format,
+            random_names: Some(self.random_names.unwrap_or(true)),
+            strict: Some(self.strict.unwrap_or(false)),
+        }
+    }
+
+    /// The default thresholds with this config's overrides applied.
+    pub fn thresholds(&self) -> Thresholds {
+        let defaults = Thresholds::default();
+
+        Thresholds {
+            hungry: self.hunger_warn.unwrap_or(defaults.hungry),
+            sad: self.happiness_warn.unwrap_or(defaults.sad),
+            exhausted: self.energy_warn.unwrap_or(defaults.exhausted),
+            unwell: self.health_warn.unwrap_or(defaults.unwell),
+            dirty: self.cleanliness_warn.unwrap_or(defaults.dirty),
+            ..defaults
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds_must_be_percentages() {
+        let config = Config {
+            happiness_warn: Some(101),
+            ..Config::default()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert_eq!(err, "happiness_warn must be between 0 and 100, got 101");
+
+        let config = Config {
+            happiness_warn: Some(100),
+            ..Config::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn unset_thresholds_keep_the_defaults() {
+        let config = Config {
+            energy_warn: Some(35),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            config.thresholds(),
+            Thresholds {
+                exhausted: 35,
+                ..Thresholds::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Why a monster died, kept in its save and memorial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CauseOfDeath {
+    /// Health ran out.
+    Neglect,
+    /// Reached the end of its lifespan, however well it was doing.
+    OldAge,
+}
+
+impl CauseOfDeath {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CauseOfDeath::Neglect => "neglect",
+            CauseOfDeath::OldAge => "old age",
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Food {
+    /// A light, healthy snack
+    Apple,
+    /// Good for health, not very filling
+    Carrot,
+    /// Very filling
+    Meat,
+    /// A balanced meal
+    Fish,
+    /// A comforting drink
+    Milk,
+}
+
+/// How a species feels about a food.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affinity {
+    /// A favorite: extra happiness.
+    Liked,
+    Neutral,
+    /// Eaten grudgingly: half the usual happiness.
+    Disliked,
+    /// Makes the monster ill.
+    Forbidden,
+}
+
+impl Affinity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Affinity::Liked => "loves it",
+            Affinity::Neutral => "fine",
+            Affinity::Disliked => "dislikes it",
+            Affinity::Forbidden => "must not eat it",
+        }
+    }
+}
+
+/// How much a single serving changes the monster's stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nutrition {
+    pub hunger: u8,
+    pub happiness: u8,
+    pub health: u8,
+}
+
+impl Food {
+    pub const ALL: [Food; 5] = [
+        Food::Apple,
+        Food::Carrot,
+        Food::Meat,
+        Food::Fish,
+        Food::Milk,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Food::Apple => "Apple",
+            Food::Carrot => "Carrot",
+            Food::Meat => "Meat",
+            Food::Fish => "Fish",
+            Food::Milk => "Milk",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Food::Apple => "🍎",
+            Food::Carrot => "🥕",
+            Food::Meat => "🍖",
+            Food::Fish => "🐟",
+            Food::Milk => "🥛",
+        }
+    }
+
+    pub fn nutrition(&self) -> Nutrition {
+        let (hunger, happiness, health) = match self {
+            Food::Apple => (20, 10, 5),
+            Food::Carrot => (15, 5, 10),
+            Food::Meat => (35, 10, 0),
+            Food::Fish => (25, 5, 10),
+            Food::Milk => (15, 15, 5),
+        };
+
+        Nutrition {
+            hunger,
+            happiness,
+            health,
+        }
+    }
+}
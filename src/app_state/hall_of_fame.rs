@@ -0,0 +1,55 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+
+use crate::app_state::{death::CauseOfDeath, monster::Monster, species::Species};
+
+/// One monster's line in the hall of fame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    pub species: Species,
+    /// Hours lived, or lived so far for the active monster.
+    pub lifespan: u32,
+    pub care_score: u8,
+    /// `None` while the monster is still going.
+    pub died_at: Option<DateTime<Utc>>,
+    pub cause_of_death: Option<CauseOfDeath>,
+}
+
+impl Entry {
+    pub fn from_monster(monster: &Monster) -> Self {
+        Self {
+            name: monster.name.clone(),
+            species: monster.species,
+            lifespan: monster.age,
+            care_score: monster.care_score(),
+            // Memorials from before `died_at` was recorded stopped updating when they died.
+            died_at: (!monster.is_alive).then(|| monster.died_at.unwrap_or(monster.updated_at)),
+            cause_of_death: monster.cause_of_death,
+        }
+    }
+
+    /// Longest-lived first, then best looked after. Name and date of death break any
+    /// remaining tie so the order never depends on which file was read first.
+    fn rank(&self, other: &Self) -> Ordering {
+        other
+            .lifespan
+            .cmp(&self.lifespan)
+            .then(other.care_score.cmp(&self.care_score))
+            .then_with(|| self.name.cmp(&other.name))
+            .then(self.died_at.cmp(&other.died_at))
+    }
+}
+
+/// Ranks the departed and, if there is one, the active monster together.
+pub fn rank(memorials: &[Monster], active: Option<&Monster>) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = memorials
+        .iter()
+        .chain(active)
+        .map(Entry::from_monster)
+        .collect();
+    entries.sort_by(Entry::rank);
+
+    entries
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Chance per simulated hour that something happens to the monster.
+pub const LIFE_EVENT_CHANCE: f64 = 0.05;
+/// Chance per simulated hour that a cold passes on its own.
+pub const COLD_RECOVERY_CHANCE: f64 = 0.1;
+/// Extra chance per simulated hour that a dirty monster catches a cold.
+pub const DIRTY_COLD_CHANCE: f64 = 0.05;
+pub const MAX_EVENT_LOG: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifeEventKind {
+    FoundTreat,
+    CaughtCold,
+    FoundCoins,
+    /// A burst of running around: happier but tired.
+    Zoomies,
+    /// Slept until fully rested and got up without being woken.
+    WokeUp,
+}
+
+impl LifeEventKind {
+    /// The events that happen at random.
+    pub const ALL: [LifeEventKind; 4] = [
+        LifeEventKind::FoundTreat,
+        LifeEventKind::CaughtCold,
+        LifeEventKind::FoundCoins,
+        LifeEventKind::Zoomies,
+    ];
+
+    pub fn describe(&self, name: &str) -> String {
+        match self {
+            LifeEventKind::FoundTreat => format!("🍪 {} found a tasty treat!", name),
+            LifeEventKind::CaughtCold => format!("🤧 {} caught a cold!", name),
+            LifeEventKind::FoundCoins => format!("🪙 {} found some coins!", name),
+            LifeEventKind::Zoomies => format!("💨 {} got the zoomies!", name),
+            LifeEventKind::WokeUp => format!("🌞 {} woke up on its own!", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LifeEvent {
+    pub kind: LifeEventKind,
+    pub at: DateTime<Utc>,
+}
@@ -0,0 +1,72 @@
+/// How grown up a monster is. Worked out from its age, so nothing about it is saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LifeStage {
+    /// The first hour.
+    Egg,
+    /// Up to a day old. Gets hungry faster.
+    Baby,
+    /// Up to three days old.
+    Child,
+    /// Up to a week old.
+    Teen,
+    /// Up to a month old.
+    Adult,
+    /// Older than a month. Illness and neglect hurt it more.
+    Elder,
+}
+
+/// Age in hours at which each stage after `Egg` begins.
+const STAGE_STARTS: [(u32, LifeStage); 5] = [
+    (1, LifeStage::Baby),
+    (24, LifeStage::Child),
+    (72, LifeStage::Teen),
+    (168, LifeStage::Adult),
+    (720, LifeStage::Elder),
+];
+
+impl LifeStage {
+    pub fn from_age(age: u32) -> Self {
+        STAGE_STARTS
+            .iter()
+            .rev()
+            .find(|&&(start, _)| age >= start)
+            .map_or(LifeStage::Egg, |&(_, stage)| stage)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LifeStage::Egg => "Egg",
+            LifeStage::Baby => "Baby",
+            LifeStage::Child => "Child",
+            LifeStage::Teen => "Teen",
+            LifeStage::Adult => "Adult",
+            LifeStage::Elder => "Elder",
+        }
+    }
+
+    /// Percentage applied to hunger gained over time, like a species tendency.
+    pub fn hunger(&self) -> u8 {
+        match self {
+            LifeStage::Baby => 150,
+            _ => 100,
+        }
+    }
+
+    /// Percentage applied to health lost to illness and neglect.
+    pub fn frailty(&self) -> u8 {
+        match self {
+            LifeStage::Elder => 150,
+            _ => 100,
+        }
+    }
+
+    /// Three columns drawn above the head, if the stage has anything to show there.
+    pub fn topper(&self) -> Option<&'static str> {
+        match self {
+            LifeStage::Baby => Some(" , "),
+            LifeStage::Teen => Some("///"),
+            LifeStage::Elder => Some("~~~"),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+const MEMORIAL_DIR: &str = ".monster-memorials";
+
+/// Moves the state file of a departed monster into the memorial directory next to it.
+pub fn archive(state_file: &Path, name: &str) -> Result<PathBuf> {
+    let dir = memorial_dir(state_file);
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create memorial directory {}", dir.display()))?;
+
+    let safe_name: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let memorial = dir.join(format!(
+        "{}-{}.json",
+        safe_name,
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    fs::rename(state_file, &memorial)
+        .with_context(|| format!("Failed to move state file to {}", memorial.display()))?;
+
+    Ok(memorial)
+}
+
+/// Archived memorials for the given state file, oldest first.
+pub fn list_memorials(state_file: &Path) -> Result<Vec<PathBuf>> {
+    let dir = memorial_dir(state_file);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut memorials = Vec::new();
+    for entry in fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read memorial directory {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            memorials.push(path);
+        }
+    }
+
+    memorials.sort();
+
+    Ok(memorials)
+}
+
+fn memorial_dir(state_file: &Path) -> PathBuf {
+    state_file
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(MEMORIAL_DIR)
+}
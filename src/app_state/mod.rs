@@ -1 +1,28 @@
+pub mod action;
+pub mod alert;
+pub mod backup;
+pub mod clock;
+pub mod comparison;
+pub mod config;
+pub mod death;
+pub mod food;
+pub mod hall_of_fame;
+pub mod life_event;
+pub mod life_stage;
+pub mod memorial;
 pub mod monster;
+pub mod name;
+pub mod need;
+pub mod neglect;
+pub mod outcome;
+pub mod passage;
+pub mod profile;
+pub mod quip;
+pub mod report;
+pub mod simulation;
+pub mod skill;
+pub mod species;
+pub mod sprite;
+pub mod storage;
+pub mod thresholds;
+pub mod undo;
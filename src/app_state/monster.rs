@@ -1,418 +1,2620 @@
 use anyhow::Result;
-use crossterm::{
-    QueueableCommand,
-    style::{Color, ResetColor, SetForegroundColor},
-};
+use crossterm::{QueueableCommand, cursor::MoveUp};
 use std::{
-    fs::{File, OpenOptions},
-    io::{self, Read, StdoutLock, Write},
-    path::Path,
+    cmp::Reverse,
+    collections::HashMap,
+    env,
+    fs::{self, File},
+    io::{self, IsTerminal, Read, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{Arc, OnceLock},
+    thread,
 };
 
-use anyhow::Context;
-use chrono::{DateTime, Utc};
-use rand::Rng;
+use anyhow::{Context, bail};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use serde::{Deserialize, Serialize};
 
-const MONSTER_STATE_FILE: &str = ".monster-state.json";
+use crate::{
+    app_state::{
+        action::Action,
+        alert::{Alert, HealthBand},
+        backup,
+        clock::{Clock, SystemClock},
+        config::Config,
+        death::CauseOfDeath,
+        food::{Affinity, Food},
+        life_event::{
+            COLD_RECOVERY_CHANCE, DIRTY_COLD_CHANCE, LIFE_EVENT_CHANCE, LifeEvent, LifeEventKind,
+            MAX_EVENT_LOG,
+        },
+        life_stage::LifeStage,
+        memorial, name,
+        need::Need,
+        neglect::Neglect,
+        outcome::{ActionOutcome, Refusal},
+        passage::TimePassage,
+        profile, quip,
+        skill::{self, MAX_SKILL_LEVEL, Skill},
+        species::{self, Species, SpeciesChoice},
+        sprite::{self, Skin},
+        storage::{self, SaveFormat},
+        thresholds::Thresholds,
+        undo,
+    },
+    terminal::prompt,
+};
+
+const DEFAULT_NAME: &str = "Fluffy";
+pub const STATS_ENV: &str = "MONS_BOX_STATS";
+const MAX_NAME_LENGTH: usize = 24;
 const STAT_DECAY_RATE: u8 = 2;
 const SLEEP_RECOVERY_RATE: u8 = 10;
-const MAX_STAT: u8 = 100;
+pub const MAX_STAT: u8 = 100;
+/// Bump when the save layout changes and add a step to `Monster::migrate`.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+/// Longest absence simulated in full unless the config says otherwise.
+pub const DEFAULT_MAX_CATCH_UP_HOURS: u32 = 1000;
+/// Lowest value time-passage decay can push a stat to. Happiness never fades to zero on its
+/// own, so an ignored monster stays recoverable; actions and health loss are not floored.
+const HAPPINESS_DECAY_FLOOR: u8 = 1;
+const ENERGY_DECAY_FLOOR: u8 = 0;
+const UNDERWEIGHT_BELOW: u8 = 30;
+const OVERWEIGHT_ABOVE: u8 = 70;
+const OVERFEEDING_HUNGER: u8 = 40;
+const OVERFEEDING_GAIN: u8 = 5;
+const TRAINING_ENERGY_COST: u8 = 20;
+const TRAINING_HAPPINESS_COST: u8 = 10;
+/// Care counts toward affection when it comes at least this long after the last counted
+/// interaction, so spamming a command doesn't farm it...
+const CARE_MIN_GAP_HOURS: i64 = 1;
+/// ...and no later than this; past it affection starts fading by a point per hour.
+const CARE_CADENCE_HOURS: i64 = 24;
+const AFFECTION_GAIN: u8 = 2;
+/// Happiness from a pet after a long enough break; petting again sooner gives less.
+const PET_MAX_BONUS: u8 = 5;
+/// A hatchling is a little grubby, so a bath is worth something from the start.
+const STARTING_CLEANLINESS: u8 = 70;
+const BATH_CLEANLINESS_GAIN: u8 = 30;
+const BATH_HAPPINESS_BONUS: u8 = 5;
+const MEDICINE_HEALTH_GAIN: u8 = 20;
+/// Happiness lost by taking medicine without being sick.
+const MEDICINE_HAPPINESS_COST: u8 = 10;
+/// Happiness bonus for day two of a streak is 1, day three 2, and so on up to this.
+const MAX_STREAK_BONUS: u8 = 10;
+/// Most extra health lost per hour once a hunger strike has gone on this many hours.
+const MAX_STRIKE_ESCALATION: u32 = 10;
+const STRIKE_HAPPINESS_DROP: u8 = 10;
+/// Extra happiness from a food the species likes.
+const LIKED_FOOD_BONUS: u8 = 5;
+/// Health lost by eating a food the species must not eat.
+const FORBIDDEN_FOOD_DAMAGE: u8 = 15;
+const HATCHING_FRAME: std::time::Duration = std::time::Duration::from_millis(400);
+const PET_RECHARGE_MINUTES: i64 = 30;
+/// How soon after a meal the monster will eat again.
+const FEED_COOLDOWN_MINUTES: i64 = 5;
+/// How soon after a game the monster will play again.
+const PLAY_COOLDOWN_MINUTES: i64 = 10;
+/// From here on the bond halves happiness decay.
+const BONDED_AFFECTION: u8 = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightCategory {
+    Underweight,
+    Healthy,
+    Overweight,
+}
+
+impl WeightCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            WeightCategory::Underweight => "🦴 Underweight",
+            WeightCategory::Healthy => "⚖️ Healthy",
+            WeightCategory::Overweight => "🍔 Overweight",
+        }
+    }
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Monster {
+    #[serde(default)]
+    pub schema_version: u32,
     pub name: String,
+    #[serde(default)]
+    pub species: Species,
+    #[serde(default)]
+    pub skin: Skin,
     pub hunger: u8,
     pub happiness: u8,
     pub energy: u8,
     pub health: u8,
+    #[serde(default = "default_weight")]
+    pub weight: u8,
+    /// Drops over time; a dirty monster falls ill more easily and gets less out of food.
+    #[serde(default = "default_cleanliness")]
+    pub cleanliness: u8,
     pub age: u32,
     pub is_sleeping: bool,
     pub is_alive: bool,
+    /// When the monster died; kept in the memorial once it is laid to rest.
+    #[serde(default)]
+    pub died_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub cause_of_death: Option<CauseOfDeath>,
+    #[serde(default)]
+    pub skills: HashMap<Skill, u8>,
+    #[serde(default)]
+    pub sick: bool,
+    #[serde(default)]
+    pub coins: u32,
+    /// Long-term bond that grows with regular care, unlike the day-to-day happiness.
+    #[serde(default)]
+    pub affection: u8,
+    #[serde(default)]
+    pub last_cared_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_petted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_fed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_played_at: Option<DateTime<Utc>>,
+    /// Consecutive local calendar days with at least one interaction.
+    #[serde(default)]
+    pub care_streak: u32,
+    #[serde(default)]
+    pub last_care_day: Option<NaiveDate>,
+    /// When hunger last hit the maximum without being fed since; drives the hunger strike.
+    #[serde(default)]
+    pub starving_since: Option<DateTime<Utc>>,
+    /// When each kind of neglect began, for as long as it lasts.
+    #[serde(default)]
+    pub neglected_since: HashMap<Neglect, DateTime<Utc>>,
+    #[serde(default)]
+    pub events: Vec<LifeEvent>,
     pub updated_at: DateTime<Utc>,
+    #[serde(skip, default = "default_clock")]
+    clock: Arc<dyn Clock>,
+    #[serde(skip, default = "default_rng")]
+    rng: StdRng,
+    #[serde(skip)]
+    pub thresholds: Thresholds,
+    #[serde(skip, default = "default_max_catch_up_hours")]
+    pub max_catch_up_hours: u32,
+    #[serde(skip)]
+    pub neglect_grace_hours: u32,
+    /// Hours until death of old age for this species, if monsters age out at all.
+    #[serde(skip)]
+    pub lifespan_hours: Option<u32>,
+    /// What catching up on load changed, for telling the player what they missed.
+    #[serde(skip)]
+    pub time_away: TimePassage,
+    /// How `save` encodes the state file.
+    #[serde(skip)]
+    pub save_format: SaveFormat,
+    /// Set by `from_env_or_default`: never read from or written to the state file.
+    #[serde(skip)]
+    pub ephemeral: bool,
 }
 
 impl Default for Monster {
     fn default() -> Self {
+        let clock = default_clock();
+        let baseline = Species::default().baseline();
+
         Self {
-            name: "Fluffy".to_string(),
-            hunger: 50,
-            happiness: 70,
-            energy: 80,
-            health: 100,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            name: DEFAULT_NAME.to_string(),
+            species: Species::default(),
+            skin: Skin::default(),
+            hunger: baseline.hunger,
+            happiness: baseline.happiness,
+            energy: baseline.energy,
+            health: baseline.health,
+            weight: baseline.weight,
+            cleanliness: STARTING_CLEANLINESS,
             age: 0,
             is_sleeping: false,
             is_alive: true,
-            updated_at: Utc::now(),
+            died_at: None,
+            cause_of_death: None,
+            skills: HashMap::new(),
+            sick: false,
+            coins: 0,
+            affection: 0,
+            last_cared_at: None,
+            last_petted_at: None,
+            last_fed_at: None,
+            last_played_at: None,
+            care_streak: 0,
+            last_care_day: None,
+            starving_since: None,
+            neglected_since: HashMap::new(),
+            events: Vec::new(),
+            updated_at: clock.now(),
+            clock,
+            rng: default_rng(),
+            thresholds: Thresholds::default(),
+            max_catch_up_hours: DEFAULT_MAX_CATCH_UP_HOURS,
+            neglect_grace_hours: 0,
+            lifespan_hours: None,
+            time_away: TimePassage::default(),
+            save_format: SaveFormat::default(),
+            ephemeral: false,
         }
     }
 }
 
+fn default_weight() -> u8 {
+    Species::default().baseline().weight
+}
+
+/// Saves from before cleanliness existed come back spotless.
+fn default_cleanliness() -> u8 {
+    MAX_STAT
+}
+
+fn default_max_catch_up_hours() -> u32 {
+    DEFAULT_MAX_CATCH_UP_HOURS
+}
+
+fn default_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Set from `--seed` for the rest of the process.
+static SEED: OnceLock<u64> = OnceLock::new();
+
+/// Seeds every later `default_rng`, so a run can be replayed. Only the first call has any
+/// effect.
+pub fn set_seed(seed: u64) {
+    let _ = SEED.set(seed);
+}
+
+/// Where every monster's randomness comes from: the `set_seed` seed if there is one,
+/// otherwise the operating system.
+pub fn default_rng() -> StdRng {
+    match SEED.get() {
+        Some(&seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    }
+}
+
 impl Monster {
-    pub fn new(name: String) -> Self {
+    pub fn new(name: String, species: Species) -> Self {
+        let baseline = species.baseline();
+
         Self {
             name,
+            species,
+            hunger: baseline.hunger,
+            happiness: baseline.happiness,
+            energy: baseline.energy,
+            health: baseline.health,
+            weight: baseline.weight,
             ..Default::default()
         }
     }
 
-    pub fn load_or_create() -> Result<Self> {
-        if Path::new(MONSTER_STATE_FILE).exists() {
-            let mut file = File::open(MONSTER_STATE_FILE)
-                .with_context(|| format!("Failed to open state file {}", MONSTER_STATE_FILE))?;
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Makes every random choice (foods, activities, life events) reproducible.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Applies the player's settings. Done before catching up, since they shape the decay.
+    pub fn configure(&mut self, config: &Config) {
+        self.thresholds = config.thresholds();
+        self.max_catch_up_hours = config
+            .max_catch_up_hours
+            .unwrap_or(DEFAULT_MAX_CATCH_UP_HOURS);
+        self.neglect_grace_hours = config.neglect_grace_hours.unwrap_or(0);
+        self.lifespan_hours = config.lifespan_hours.map(|hours| {
+            (hours as u64 * self.species.tendencies().lifespan as u64 / 100).min(u32::MAX as u64)
+                as u32
+        });
+        if let Some(format) = config.save_format {
+            self.save_format = format;
+        }
+    }
+
+    /// Builds a monster that only lives in memory, for demos and screenshots; `save` and
+    /// `remember_for_undo` leave it alone. `stats`, or `$MONS_BOX_STATS` when that is `None`,
+    /// is a JSON object whose fields override those of a freshly hatched monster, e.g.
+    /// `{"name": "Demo", "hunger": 90}`.
+    pub fn from_env_or_default(
+        stats: Option<&str>,
+        species: SpeciesChoice,
+        config: &Config,
+    ) -> Result<Self> {
+        let mut rng = default_rng();
+        let species = species.resolve(&mut rng);
+        let mut monster = Monster {
+            rng: rng.clone(),
+            ..Monster::new(DEFAULT_NAME.to_string(), species)
+        };
+
+        let stats = stats
+            .map(str::to_string)
+            .or_else(|| env::var(STATS_ENV).ok().filter(|stats| !stats.is_empty()));
+        if let Some(stats) = stats {
+            let overrides: serde_json::Value =
+                serde_json::from_str(&stats).context("Failed to parse the stats JSON")?;
+            let Some(overrides) = overrides.as_object() else {
+                bail!("The stats JSON must be an object, like {{\"hunger\": 90}}");
+            };
+
+            let mut fields = serde_json::to_value(&monster)?;
+            if let Some(fields) = fields.as_object_mut() {
+                fields.extend(overrides.clone());
+            }
+            monster = Monster {
+                rng,
+                ..serde_json::from_value(fields).context("Invalid stats JSON")?
+            };
+        }
+
+        monster.ephemeral = true;
+        monster.migrate();
+        monster.configure(config);
+        monster.time_away = monster.update_from_time_passage();
+
+        Ok(monster)
+    }
+
+    /// Loads the saved monster and catches it up to now without writing anything back.
+    /// Returns `None` when no monster has been created yet. A damaged save is recovered
+    /// from the undo snapshot or a backup where possible, see `recover`.
+    pub fn load(config: &Config) -> Result<Option<Self>> {
+        let path = storage::state_file();
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let state = match read_state(&path) {
+            Ok(state) => state,
+            Err(err) => match Self::recover(&path, err, config)? {
+                Some(state) => state,
+                None => return Ok(None),
+            },
+        };
+
+        Ok(Some(Self::caught_up(state, config)))
+    }
+
+    /// Like `load`, but from any state file, such as a backup or another player's export.
+    /// The file is only read: a damaged one is an error rather than something to recover.
+    pub fn load_from(path: &Path, config: &Config) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::caught_up(read_state(path)?, config)))
+    }
+
+    fn caught_up((mut monster, format): (Self, SaveFormat), config: &Config) -> Self {
+        monster.save_format = format;
+        monster.migrate();
+        monster.configure(config);
+        monster.time_away = monster.update_from_time_passage();
+        monster
+    }
+
+    /// Called when the state file can't be read, e.g. after it was cut short. Falls back to the
+    /// undo snapshot and then the backups, newest first, keeping the damaged file as
+    /// `<state file>.bak` either way. When nothing is readable the game starts over
+    /// (`None`), after asking first if both stdin and stdout are a terminal. With `strict` in
+    /// the config the original error is returned instead.
+    fn recover(
+        path: &Path,
+        err: anyhow::Error,
+        config: &Config,
+    ) -> Result<Option<(Self, SaveFormat)>> {
+        let mut candidates = vec![undo::undo_file(path)];
+        candidates.extend(backup::list_backups(path).unwrap_or_default());
+
+        let corrupt = storage::corrupt_file(path);
+        for candidate in candidates.iter().filter(|candidate| candidate.exists()) {
+            if let Ok(state) = read_state(candidate) {
+                fs::copy(path, &corrupt).with_context(|| {
+                    format!(
+                        "Failed to keep the damaged state file as {}",
+                        corrupt.display()
+                    )
+                })?;
+                eprintln!(
+                    "⚠️ {} couldn't be read ({:#}); recovered from {}. The damaged file was kept as {}.",
+                    path.display(),
+                    err,
+                    candidate.display(),
+                    corrupt.display()
+                );
+                return Ok(Some(state));
+            }
+        }
+
+        let err = err.context(format!(
+            "{} is damaged and no backup could be read. Repair or remove it, or pick a backup with `restore`",
+            path.display()
+        ));
+        if config.strict.unwrap_or(false) {
+            return Err(err);
+        }
 
-            let mut content = String::new();
-            file.read_to_string(&mut content)
-                .with_context(|| "Failed to read state file")?;
+        if io::stdin().is_terminal() && io::stdout().is_terminal() {
+            eprintln!("⚠️ {:#}", err);
+            if !prompt::confirm(&format!(
+                "Start over with a new monster? The damaged file will be kept as {}.",
+                corrupt.display()
+            )) {
+                return Err(err);
+            }
+        } else {
+            eprintln!(
+                "⚠️ {} is damaged and no backup could be read ({:#}). Starting over with a new monster; the damaged file was kept as {}.",
+                path.display(),
+                err.root_cause(),
+                corrupt.display()
+            );
+        }
 
-            let mut monster: Monster =
-                serde_json::from_str(&content).with_context(|| "Failed to parse state file")?;
+        fs::rename(path, &corrupt).with_context(|| {
+            format!(
+                "Failed to move the damaged state file to {}",
+                corrupt.display()
+            )
+        })?;
+        Ok(None)
+    }
 
-            monster.update_from_time_passage()?;
+    /// Loads the saved monster, or hatches one of the chosen species called `name`. Without a
+    /// name the player is asked for one, unless stdin isn't a terminal, in which case the
+    /// default name is used rather than waiting for input. With `intro`, a terminal first gets
+    /// to watch the egg crack.
+    pub fn load_or_create(
+        species: SpeciesChoice,
+        config: &Config,
+        intro: bool,
+        name: Option<&str>,
+    ) -> Result<Self> {
+        let _timer = profile::Timer::start("load_or_create");
+        let path = storage::state_file();
+
+        if let Some(monster) = Self::load(config)? {
             monster.save()?;
 
             Ok(monster)
         } else {
-            println!("🥚 A new monster has hatched! What would you like to name them?");
-            println!("Name: ");
-            io::stdout().flush()?;
+            storage::ensure_state_dir(&path)?;
+
+            if intro && io::stdout().is_terminal() {
+                play_hatching(&mut io::stdout().lock())?;
+            }
 
-            let mut name = String::new();
-            io::stdin().read_line(&mut name)?;
-            let name = name.trim().to_string();
+            let name = match name {
+                Some(name) => sanitize_name(name),
+                None if io::stdin().is_terminal() => {
+                    println!("🥚 A new monster has hatched! What would you like to name them?");
+                    println!("Name: ");
+                    io::stdout().flush()?;
+                    read_name()
+                }
+                None => None,
+            };
 
-            let monster = if name.is_empty() {
-                Monster::default()
-            } else {
-                Monster::new(name)
+            let mut rng = default_rng();
+            let name = name.unwrap_or_else(|| {
+                if config.random_names.unwrap_or(true) {
+                    name::generate(&mut rng)
+                } else {
+                    DEFAULT_NAME.to_string()
+                }
+            });
+
+            let species = species.resolve(&mut rng);
+            let mut monster = Monster {
+                rng,
+                save_format: SaveFormat::from_extension(&path),
+                ..Monster::new(name, species)
             };
+            monster.configure(config);
 
             monster.save()?;
-            println!("🎉 Meet {}! Take good care of them!", monster.name);
+            println!(
+                "🎉 Meet {} the {}! Take good care of them!",
+                monster.name, monster.species
+            );
 
             Ok(monster)
         }
     }
 
+    /// Upgrades a monster loaded from an older save to the current layout. Fields added since
+    /// the save was written already hold their serde defaults by the time this runs.
+    pub fn migrate(&mut self) {
+        if self.schema_version < 1 {
+            // Unversioned saves were never validated, so hand-edited stats may be out of range.
+            self.hunger = self.hunger.min(MAX_STAT);
+            self.happiness = self.happiness.min(MAX_STAT);
+            self.energy = self.energy.min(MAX_STAT);
+            self.health = self.health.min(MAX_STAT);
+            self.is_alive = self.is_alive && self.health > 0;
+        }
+
+        if self.schema_version < 2 && !self.is_alive && self.cause_of_death.is_none() {
+            // Before lifespans, running out of health was the only way to go.
+            self.cause_of_death = Some(CauseOfDeath::Neglect);
+        }
+
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+    }
+
     pub fn save(&self) -> Result<()> {
-        let mut file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(MONSTER_STATE_FILE)
-            .with_context(|| format!("Failed to create/open state file {}", MONSTER_STATE_FILE))?;
-
-        let json = serde_json::to_string_pretty(self)
+        let _timer = profile::Timer::start("save");
+        if self.ephemeral {
+            return Ok(());
+        }
+
+        let path = storage::state_file();
+        storage::ensure_state_dir(&path)?;
+
+        let content = self
+            .save_format
+            .serialize(self)
             .with_context(|| "Failed to serialize monster state")?;
 
-        file.write_all(json.as_bytes())
-            .with_context(|| "Failed to write monster state to file")?;
+        let _guard = storage::SAVE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
 
-        Ok(())
+        storage::write_atomic(&path, content.as_bytes())
+            .with_context(|| "Failed to write monster state to file")
     }
 
-    pub fn update_from_time_passage(&mut self) -> Result<()> {
-        let now = Utc::now();
+    /// Applies decay for every whole hour since `updated_at` and reports what changed. Only
+    /// the consumed hours are moved into `updated_at`, so the leftover minutes carry over to
+    /// the next update and frequent runs still add up.
+    ///
+    /// A dead monster stays exactly as it died; only `updated_at` moves on.
+    ///
+    /// Age always grows by the full elapsed time. Decay and life events are simulated for at
+    /// most `max_catch_up_hours`; every stat saturates long before that, so a longer gap ends
+    /// in the same state it would have reached anyway.
+    ///
+    /// Per-hour changes, where `d` is `STAT_DECAY_RATE`:
+    ///
+    /// | stat        | awake              | asleep                      |
+    /// |-------------|--------------------|-----------------------------|
+    /// | hunger      | +d                 | +d/2                        |
+    /// | happiness   | -d/2 (Discipline)  | -d/4 (Discipline), boredom  |
+    /// | energy      | -d (Fitness)       | +`SLEEP_RECOVERY_RATE`/2    |
+    /// | cleanliness | -d/2               | -d/4                        |
+    ///
+    /// A sleeping monster gets up on its own the hour its energy is full and spends the rest
+    /// of the time awake.
+    ///
+    /// Health only moves through weight, sickness, neglect and hunger strikes, the same way in
+    /// both states. Neglect is hunger above 80, happiness below 20, energy below 10, or
+    /// cleanliness below `Thresholds::dirty`; it only hurts once it has lasted
+    /// `neglect_grace_hours`.
+    /// Hunger gain, awake energy loss and health loss are then scaled by species tendencies.
+    pub fn update_from_time_passage(&mut self) -> TimePassage {
+        let _timer = profile::Timer::start("update_from_time_passage");
+        let now = self.clock.now();
         let time_passed = now.signed_duration_since(self.updated_at);
         let hours_passed = time_passed.num_hours();
 
-        if hours_passed > 0 {
-            let hours_clamped = (hours_passed as u32).min(1000);
-            self.age = self.age.saturating_add(hours_clamped);
-
-            let decay_amount =
-                ((hours_clamped as u32 * STAT_DECAY_RATE as u32) / 1).min(MAX_STAT as u32) as u8;
-            let recovery_amount = ((hours_clamped as u32 * SLEEP_RECOVERY_RATE as u32) / 2)
-                .min(MAX_STAT as u32) as u8;
-
+        if hours_passed > 0 && !self.is_alive {
+            self.updated_at += Duration::hours(hours_passed);
+        } else if hours_passed > 0 {
+            let (hunger, happiness, energy, health) =
+                (self.hunger, self.happiness, self.energy, self.health);
+            let hours_elapsed = u32::try_from(hours_passed).unwrap_or(u32::MAX);
+            let hours_clamped = hours_elapsed.min(self.max_catch_up_hours);
+            let age_before = self.age;
+            self.age = self.age.saturating_add(hours_elapsed);
+
+            let decay_amount = (hours_clamped * STAT_DECAY_RATE as u32).min(MAX_STAT as u32) as u8;
+            let tendencies = self.species.tendencies();
+            let stage = LifeStage::from_age(age_before);
+
+            let mut events = Vec::new();
+            let mut hours_asleep = 0;
             if self.is_sleeping {
-                self.energy = (self.energy.saturating_add(recovery_amount)).min(MAX_STAT);
-                self.hunger = (self.hunger.saturating_add(decay_amount / 2)).min(MAX_STAT);
+                hours_asleep = hours_clamped.min(self.hours_until_rested());
+                self.sleep_for(hours_asleep, age_before, stage);
+                if self.energy == MAX_STAT {
+                    self.is_sleeping = false;
+                    let at = self.updated_at + Duration::hours(hours_asleep as i64);
+                    self.log_event(LifeEventKind::WokeUp, at);
+                    events.push(LifeEvent {
+                        kind: LifeEventKind::WokeUp,
+                        at,
+                    });
+                    self.stay_awake_for(hours_clamped - hours_asleep, stage);
+                }
             } else {
-                self.hunger = (self.hunger.saturating_add(decay_amount)).min(MAX_STAT);
-                self.happiness = (self.happiness.saturating_sub(decay_amount / 2)).max(1);
-                self.energy = (self.energy.saturating_sub(decay_amount)).max(0);
+                self.stay_awake_for(hours_clamped, stage);
+            }
+
+            if self.hunger > 80 {
+                self.weight = self.weight.saturating_sub((decay_amount / 2).max(1));
+            }
+
+            let illness_damage = species::scale(
+                species::scale((decay_amount / 2).max(1), tendencies.health),
+                stage.frailty(),
+            );
+            if self.weight_category() == WeightCategory::Underweight {
+                self.health = self.health.saturating_sub(illness_damage);
+            }
+
+            if self.sick {
+                self.health = self.health.saturating_sub(illness_damage);
             }
 
-            if self.hunger > 80 || self.happiness < 20 || self.energy < 10 {
-                self.health = self.health.saturating_sub((decay_amount * 2).max(1));
+            self.fade_affection(hours_clamped);
+
+            // Nothing else happens while the monster sleeps.
+            for hour in hours_asleep..hours_clamped {
+                if !self.is_alive || self.health == 0 {
+                    break;
+                }
+                let at = self.updated_at + Duration::hours(hour as i64 + 1);
+                if let Some(kind) = self.roll_life_event(at) {
+                    events.push(LifeEvent { kind, at });
+                }
+            }
+            events.sort_by_key(|event| event.at);
+
+            if self.track_neglect(
+                self.updated_at,
+                self.updated_at + Duration::hours(hours_passed),
+            ) {
+                let neglect_damage = species::scale(
+                    species::scale((decay_amount * 2).max(1), tendencies.health),
+                    stage.frailty(),
+                );
+                self.health = self.health.saturating_sub(neglect_damage);
             }
 
+            self.hunger_strike(now, hours_clamped);
+
             if self.health == 0 {
-                self.is_alive = false;
+                self.die(now, CauseOfDeath::Neglect);
+            } else if self
+                .lifespan_hours
+                .is_some_and(|lifespan| self.age >= lifespan)
+            {
+                self.die(now, CauseOfDeath::OldAge);
             }
+
+            self.updated_at += Duration::hours(hours_passed);
+
+            return TimePassage {
+                hours: hours_elapsed,
+                hunger: self.hunger as i16 - hunger as i16,
+                happiness: self.happiness as i16 - happiness as i16,
+                energy: self.energy as i16 - energy as i16,
+                health: self.health as i16 - health as i16,
+                died: !self.is_alive,
+                events,
+            };
+        } else if time_passed < Duration::zero() {
+            self.updated_at = now;
         }
 
-        self.updated_at = now;
+        TimePassage::default()
+    }
 
-        Ok(())
+    /// Records the death, unless the monster already died, whose time and cause stand.
+    fn die(&mut self, at: DateTime<Utc>, cause: CauseOfDeath) {
+        if !self.is_alive {
+            return;
+        }
+        self.is_alive = false;
+        self.died_at = Some(at);
+        self.cause_of_death = Some(cause);
+    }
+
+    fn is_neglected(&self, neglect: Neglect) -> bool {
+        match neglect {
+            Neglect::Hunger => self.hunger > 80,
+            Neglect::Happiness => self.happiness < 20,
+            Neglect::Energy => self.energy < 10,
+            Neglect::Cleanliness => self.is_dirty(),
+        }
+    }
+
+    /// Updates when each kind of neglect began for an update covering `start..end`, and
+    /// whether any has now outlasted the grace period. Neglect first seen in this update is
+    /// assumed to have begun at `start`, since the stats are only checked at the end.
+    fn track_neglect(&mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        for neglect in Neglect::ALL {
+            if self.is_neglected(neglect) {
+                self.neglected_since.entry(neglect).or_insert(start);
+            } else {
+                self.neglected_since.remove(&neglect);
+            }
+        }
+
+        let grace = Duration::hours(self.neglect_grace_hours as i64);
+        self.neglected_since
+            .values()
+            .any(|since| end.signed_duration_since(*since) >= grace)
+    }
+
+    /// Escalates the penalty for leaving hunger maxed out: every hour of the strike adds a
+    /// point of health loss per hour (up to `MAX_STRIKE_ESCALATION`), and happiness drops
+    /// sharply. The first update that finds the monster starving only starts the clock.
+    fn hunger_strike(&mut self, now: DateTime<Utc>, hours: u32) {
+        if self.hunger < MAX_STAT {
+            self.starving_since = None;
+            return;
+        }
+
+        let Some(since) = self.starving_since else {
+            self.starving_since = Some(now);
+            return;
+        };
+
+        let strike_hours = u32::try_from(now.signed_duration_since(since).num_hours()).unwrap_or(0);
+        let escalation = strike_hours.min(MAX_STRIKE_ESCALATION);
+        let health_loss = (escalation * hours).min(MAX_STAT as u32) as u8;
+        let happiness_loss = (STRIKE_HAPPINESS_DROP as u32 * hours).min(MAX_STAT as u32) as u8;
+
+        self.health = self.health.saturating_sub(health_loss);
+        self.happiness = decay_stat(self.happiness, happiness_loss, HAPPINESS_DECAY_FLOOR);
+    }
+
+    fn bond_dampen(&self, happiness_decay: u8) -> u8 {
+        if self.affection >= BONDED_AFFECTION {
+            happiness_decay / 2
+        } else {
+            happiness_decay
+        }
+    }
+
+    /// Takes a point of affection for every hour of the next `hours` that falls more than
+    /// `CARE_CADENCE_HOURS` after the last counted interaction.
+    fn fade_affection(&mut self, hours: u32) {
+        let Some(last_cared_at) = self.last_cared_at else {
+            return;
+        };
+
+        let window_end = self.updated_at + Duration::hours(hours as i64);
+        let neglect_from =
+            (last_cared_at + Duration::hours(CARE_CADENCE_HOURS)).max(self.updated_at);
+        let neglected = window_end
+            .signed_duration_since(neglect_from)
+            .num_hours()
+            .max(0);
+
+        self.affection = self
+            .affection
+            .saturating_sub(neglected.min(MAX_STAT as i64) as u8);
+    }
+
+    /// Records a successful interaction, growing affection if it keeps up the care cadence.
+    fn cared_for(&mut self) {
+        let now = self.clock.now();
+        self.extend_streak(now.with_timezone(&Local).date_naive());
+
+        if let Some(last) = self.last_cared_at {
+            let gap = now.signed_duration_since(last);
+            if gap < Duration::hours(CARE_MIN_GAP_HOURS) {
+                return;
+            }
+            if gap <= Duration::hours(CARE_CADENCE_HOURS) {
+                self.affection = (self.affection.saturating_add(AFFECTION_GAIN)).min(MAX_STAT);
+            }
+        }
+
+        self.last_cared_at = Some(now);
+    }
+
+    /// Counts `today` toward the daily care streak. The first interaction of each new day in a
+    /// row pays a happiness bonus that grows with the streak; skipping a day starts over.
+    fn extend_streak(&mut self, today: NaiveDate) {
+        let continues = match self.last_care_day {
+            Some(day) if day == today => return,
+            Some(day) => day.succ_opt() == Some(today),
+            None => false,
+        };
+
+        self.care_streak = if continues { self.care_streak + 1 } else { 1 };
+        self.last_care_day = Some(today);
+
+        if self.care_streak > 1 {
+            let bonus = (self.care_streak - 1).min(MAX_STREAK_BONUS as u32) as u8;
+            self.happiness = (self.happiness.saturating_add(bonus)).min(MAX_STAT);
+        }
+    }
+
+    /// Whole hours of sleep until energy is full, and at least one so a monster put to bed
+    /// rested still gets a nap.
+    fn hours_until_rested(&self) -> u32 {
+        let missing = (MAX_STAT - self.energy.min(MAX_STAT)) as u32;
+        (missing * 2).div_ceil(SLEEP_RECOVERY_RATE as u32).max(1)
+    }
+
+    /// The asleep column of the table on `update_from_time_passage`, for `hours` from `age`.
+    fn sleep_for(&mut self, hours: u32, age: u32, stage: LifeStage) {
+        let decay_amount = (hours * STAT_DECAY_RATE as u32).min(MAX_STAT as u32) as u8;
+        let tendencies = self.species.tendencies();
+        let recovery_amount = ((hours * SLEEP_RECOVERY_RATE as u32) / 2).min(MAX_STAT as u32) as u8;
+
+        self.energy = (self.energy.saturating_add(recovery_amount)).min(MAX_STAT);
+        let hunger_gain = species::scale(
+            species::scale(decay_amount / 2, tendencies.hunger),
+            stage.hunger(),
+        );
+        self.hunger = (self.hunger.saturating_add(hunger_gain)).min(MAX_STAT);
+
+        // A sleeping monster still gets bored, so sleep can't be used to park happiness.
+        let boredom = sleep_boredom(age, hours);
+        let boredom = self.bond_dampen(skill::reduce_decay(
+            boredom,
+            self.skill_level(Skill::Discipline),
+        ));
+        self.happiness = decay_stat(self.happiness, boredom, HAPPINESS_DECAY_FLOOR);
+        self.cleanliness = decay_stat(self.cleanliness, decay_amount / 4, 0);
+    }
+
+    /// The awake column of the table on `update_from_time_passage`.
+    fn stay_awake_for(&mut self, hours: u32, stage: LifeStage) {
+        let decay_amount = (hours * STAT_DECAY_RATE as u32).min(MAX_STAT as u32) as u8;
+        let tendencies = self.species.tendencies();
+
+        let hunger_gain = species::scale(
+            species::scale(decay_amount, tendencies.hunger),
+            stage.hunger(),
+        );
+        self.hunger = (self.hunger.saturating_add(hunger_gain)).min(MAX_STAT);
+        let happiness_decay = self.bond_dampen(skill::reduce_decay(
+            decay_amount / 2,
+            self.skill_level(Skill::Discipline),
+        ));
+        let energy_decay = skill::reduce_decay(
+            species::scale(decay_amount, tendencies.energy),
+            self.skill_level(Skill::Fitness),
+        );
+
+        self.happiness = decay_stat(self.happiness, happiness_decay, HAPPINESS_DECAY_FLOOR);
+        self.energy = decay_stat(self.energy, energy_decay, ENERGY_DECAY_FLOOR);
+        self.cleanliness = decay_stat(self.cleanliness, decay_amount / 2, 0);
+    }
+
+    /// Gives something a chance to happen in the hour ending at `at`, and returns what did.
+    /// Nothing happens to a dead monster.
+    fn roll_life_event(&mut self, at: DateTime<Utc>) -> Option<LifeEventKind> {
+        if !self.is_alive {
+            return None;
+        }
+
+        if self.sick && self.rng.random_bool(COLD_RECOVERY_CHANCE) {
+            self.sick = false;
+        }
+
+        let kind = if self.is_dirty() && !self.sick && self.rng.random_bool(DIRTY_COLD_CHANCE) {
+            LifeEventKind::CaughtCold
+        } else if self.rng.random_bool(LIFE_EVENT_CHANCE) {
+            LifeEventKind::ALL[self.rng.random_range(0..LifeEventKind::ALL.len())]
+        } else {
+            return None;
+        };
+        match kind {
+            LifeEventKind::FoundTreat => {
+                self.happiness = (self.happiness + 10).min(MAX_STAT);
+                self.hunger = self.hunger.saturating_sub(10);
+            }
+            LifeEventKind::CaughtCold => self.sick = true,
+            LifeEventKind::FoundCoins => self.coins += self.rng.random_range(1..=10),
+            LifeEventKind::Zoomies => {
+                self.happiness = (self.happiness + 10).min(MAX_STAT);
+                self.energy = self.energy.saturating_sub(10);
+            }
+            LifeEventKind::WokeUp => {}
+        }
+
+        self.log_event(kind, at);
+        Some(kind)
+    }
+
+    /// Adds an event to the log, keeping it in time order and no longer than `MAX_EVENT_LOG`.
+    fn log_event(&mut self, kind: LifeEventKind, at: DateTime<Utc>) {
+        let index = self.events.partition_point(|event| event.at <= at);
+        self.events.insert(index, LifeEvent { kind, at });
+        if self.events.len() > MAX_EVENT_LOG {
+            self.events.remove(0);
+        }
+    }
+
+    /// Stores the current state in the undo slot; call right before an action changes it.
+    pub fn remember_for_undo(&self) -> Result<()> {
+        if self.ephemeral {
+            return Ok(());
+        }
+
+        let content = self
+            .save_format
+            .serialize(self)
+            .with_context(|| "Failed to serialize monster state")?;
+        undo::remember(&storage::state_file(), &content)
+    }
+
+    /// Puts back the state saved by the last `remember_for_undo`. Returns `false` if there is
+    /// nothing to undo.
+    pub fn undo() -> Result<bool> {
+        undo::restore(&storage::state_file())
     }
 
     pub fn reset() -> Result<()> {
-        if Path::new(MONSTER_STATE_FILE).exists() {
-            std::fs::remove_file(MONSTER_STATE_FILE)
-                .with_context(|| "Failed to remove state file")?;
+        // The undo slot belongs to the old monster; restoring it would undo the reset.
+        undo::clear(&storage::state_file())?;
+
+        if let Some(backup) = backup::backup_state_file(&storage::state_file())? {
+            println!("💾 Your old monster was backed up to {}", backup.display());
         }
 
         println!("💫 Starting fresh with a new monster!");
         Ok(())
     }
 
-    pub fn feed(&mut self) -> String {
+    /// Final stats and achievements of a monster, one line each, for its memorial.
+    pub fn epitaph(&self) -> Vec<String> {
+        let mastered = self.mastered_skills();
+
+        vec![
+            format!("🪦 In loving memory of {} the {}", self.name, self.species),
+            match self.cause_of_death {
+                Some(cause) => format!("Lived {} hours, until {}", self.age, cause.label()),
+                None => format!("Lived {} hours", self.age),
+            },
+            format!(
+                "Final stats: hunger {}, happiness {}, energy {}, health {}, weight {}",
+                self.hunger, self.happiness, self.energy, self.health, self.weight
+            ),
+            format!("Skills: {}", self.skills_summary()),
+            format!(
+                "Mastered: {}",
+                if mastered.is_empty() {
+                    "nothing yet".to_string()
+                } else {
+                    join_words(&mastered)
+                }
+            ),
+            format!("Coins saved: {}", self.coins),
+            format!("Bond: {}%", self.affection),
+            format!("Adventures: {}", self.events.len()),
+        ]
+    }
+
+    /// The numbers behind the status screen plus the monster's tastes and milestones, one
+    /// line each, for the interactive detail panel.
+    pub fn details(&self) -> Vec<String> {
+        let foods_with = |affinity: Affinity| {
+            let foods: Vec<&str> = Food::ALL
+                .iter()
+                .filter(|food| self.species.affinity(**food) == affinity)
+                .map(|food| food.name())
+                .collect();
+            if foods.is_empty() {
+                "nothing".to_string()
+            } else {
+                join_words(&foods)
+            }
+        };
+        let local_time = |time: Option<DateTime<Utc>>| match time {
+            Some(time) => time
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            None => "never".to_string(),
+        };
+        let mastered = self.mastered_skills();
+
+        vec![
+            format!(
+                "Stats: hunger {}, happiness {}, energy {}, health {}, cleanliness {}",
+                self.hunger, self.happiness, self.energy, self.health, self.cleanliness
+            ),
+            format!(
+                "Stage: {}; care score: {}%, bond: {}%, weight: {}",
+                self.life_stage().name(),
+                self.care_score(),
+                self.affection,
+                self.weight
+            ),
+            format!(
+                "Likes: {}; dislikes: {}",
+                foods_with(Affinity::Liked),
+                foods_with(Affinity::Disliked)
+            ),
+            format!(
+                "Mastered: {}; adventures: {}; care streak: {} day{}",
+                if mastered.is_empty() {
+                    "nothing yet".to_string()
+                } else {
+                    join_words(&mastered)
+                },
+                self.events.len(),
+                self.care_streak,
+                if self.care_streak == 1 { "" } else { "s" }
+            ),
+            format!("Last cared for: {}", local_time(self.last_cared_at)),
+            format!("Last updated: {}", local_time(Some(self.updated_at))),
+        ]
+    }
+
+    fn mastered_skills(&self) -> Vec<&'static str> {
+        Skill::ALL
+            .iter()
+            .filter(|skill| self.skill_level(**skill) >= MAX_SKILL_LEVEL)
+            .map(|skill| skill.name())
+            .collect()
+    }
+
+    /// Archives a dead monster's save into the memorial directory and empties the active
+    /// slot, so the next command hatches a new one.
+    pub fn lay_to_rest(&self) -> Result<PathBuf> {
+        let state_file = storage::state_file();
+        undo::clear(&state_file)?;
+        memorial::archive(&state_file, &self.name)
+    }
+
+    /// Every monster laid to rest so far, as it was when it died. Memorials that can't be
+    /// read are reported and skipped rather than hiding all the others.
+    pub fn memorials() -> Result<Vec<Self>> {
+        let mut monsters = Vec::new();
+        for path in memorial::list_memorials(&storage::state_file())? {
+            match read_state(&path) {
+                Ok((mut monster, _)) => {
+                    monster.migrate();
+                    monsters.push(monster);
+                }
+                Err(err) => eprintln!("Skipping memorial {}: {:#}", path.display(), err),
+            }
+        }
+
+        Ok(monsters)
+    }
+
+    /// Every pet in the data directory with its slot, caught up to now but not saved. Saves
+    /// that can't be read are reported and skipped.
+    pub fn pets(config: &Config) -> Result<Vec<(Option<String>, Self)>> {
+        let mut pets = Vec::new();
+        for (slot, path) in storage::list_pets()? {
+            match read_state(&path) {
+                Ok((mut monster, _)) => {
+                    monster.migrate();
+                    monster.configure(config);
+                    monster.update_from_time_passage();
+                    pets.push((slot, monster));
+                }
+                Err(err) => eprintln!("Skipping {}: {:#}", path.display(), err),
+            }
+        }
+
+        Ok(pets)
+    }
+
+    pub fn backups() -> Result<Vec<PathBuf>> {
+        backup::list_backups(&storage::state_file())
+    }
+
+    pub fn restore(backup: &Path) -> Result<()> {
+        backup::restore_backup(&storage::state_file(), backup)
+    }
+
+    pub fn apply(&mut self, action: Action) -> ActionOutcome {
+        match action {
+            Action::Feed => self.feed(),
+            Action::Play => self.play(),
+            Action::Pet => self.pet(),
+            Action::Bath => self.bath(),
+            Action::Medicine => self.medicine(),
+            Action::ToggleSleep => self.toggle_sleep(),
+            Action::Train(skill) => self.train(skill),
+            Action::Wait => ActionOutcome::Success {
+                message: format!("⏳ {} is waiting.", self.name),
+            },
+        }
+    }
+
+    /// Feeds a randomly chosen food, never one the species must not eat.
+    pub fn feed(&mut self) -> ActionOutcome {
+        let safe: Vec<Food> = Food::ALL
+            .into_iter()
+            .filter(|&food| self.species.affinity(food) != Affinity::Forbidden)
+            .collect();
+        let food = safe[self.rng.random_range(0..safe.len())];
+        self.feed_with(food)
+    }
+
+    pub fn feed_with(&mut self, food: Food) -> ActionOutcome {
         if !self.is_alive {
-            return format!("💀 {} has passed away...", self.name);
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
         }
 
         if self.is_sleeping {
-            return format!("😴 {} is sleeping peacefully. Try again later!", self.name);
+            return self.refuse(Refusal::Sleeping);
+        }
+
+        if let Some(remaining) = self.cooldown_remaining(Action::Feed) {
+            return self.refuse(Refusal::NotReady(remaining));
         }
 
         if self.hunger <= 20 {
             self.happiness = self.happiness.saturating_sub(5);
-            return format!("🤢 {} is too full to eat more!", self.name);
+            return self.refuse(Refusal::TooFull);
         }
 
-        self.hunger = self.hunger.saturating_sub(25);
-        self.happiness = (self.happiness + 10).min(MAX_STAT);
-        self.health = (self.health + 5).min(MAX_STAT);
+        if self.hunger < OVERFEEDING_HUNGER {
+            self.weight = (self.weight.saturating_add(OVERFEEDING_GAIN)).min(MAX_STAT);
+        }
 
-        let foods = ["🍎", "🥕", "🍖", "🐟", "🥛"];
-        let food = foods[rand::rng().random_range(0..foods.len())];
+        let nutrition = food.nutrition();
+        let affinity = self.species.affinity(food);
+        // Eating while dirty does the body less good.
+        let health_gain = if self.is_dirty() {
+            nutrition.health / 2
+        } else {
+            nutrition.health
+        };
+        self.hunger = self.hunger.saturating_sub(nutrition.hunger);
+        self.starving_since = None;
+        self.last_fed_at = Some(self.clock.now());
+        self.cared_for();
+
+        let message = match affinity {
+            Affinity::Forbidden => {
+                self.health = self.health.saturating_sub(FORBIDDEN_FOOD_DAMAGE);
+                if self.health == 0 {
+                    self.die(self.clock.now(), CauseOfDeath::Neglect);
+                }
+                format!(
+                    "⚠️ {} ate {} but {}s can't stomach it! Health -{}.",
+                    self.name,
+                    food.emoji(),
+                    self.species,
+                    FORBIDDEN_FOOD_DAMAGE
+                )
+            }
+            Affinity::Disliked => {
+                self.happiness =
+                    (self.happiness.saturating_add(nutrition.happiness / 2)).min(MAX_STAT);
+                self.health = (self.health.saturating_add(health_gain)).min(MAX_STAT);
+                format!("😒 {} ate {}, reluctantly.", self.name, food.emoji())
+            }
+            Affinity::Liked => {
+                self.happiness = (self
+                    .happiness
+                    .saturating_add(nutrition.happiness + LIKED_FOOD_BONUS))
+                .min(MAX_STAT);
+                self.health = (self.health.saturating_add(health_gain)).min(MAX_STAT);
+                format!("😋 {} gobbled up {}, a favorite!", self.name, food.emoji())
+            }
+            Affinity::Neutral => {
+                self.happiness = (self.happiness.saturating_add(nutrition.happiness)).min(MAX_STAT);
+                self.health = (self.health.saturating_add(health_gain)).min(MAX_STAT);
+                format!("{} ate {} and feels much better!", self.name, food.emoji())
+            }
+        };
 
-        format!("{} ate {} and feels much better!", self.name, food)
+        ActionOutcome::Success { message }
     }
 
-    pub fn play(&mut self) -> String {
+    pub fn play(&mut self) -> ActionOutcome {
         if !self.is_alive {
-            return format!("💀 {} has passed away...", self.name);
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
         }
 
         if self.is_sleeping {
-            return format!("😴 {} is sleeping peacefully. Try again later!", self.name);
+            return self.refuse(Refusal::Sleeping);
+        }
+
+        if let Some(remaining) = self.cooldown_remaining(Action::Play) {
+            return self.refuse(Refusal::NotReady(remaining));
         }
 
         if self.energy < 20 {
-            return format!("😫 {} is too tired to play right now!", self.name);
+            return self.refuse(Refusal::TooTiredToPlay);
         }
 
         if self.hunger > 80 {
-            return format!("😵 {} is too hungry to play! Feed them first!", self.name);
+            return self.refuse(Refusal::TooHungryToPlay);
         }
 
+        let energy_cost = match self.weight_category() {
+            WeightCategory::Overweight => 25,
+            _ => 15,
+        };
+
         self.happiness = (self.happiness + 20).min(MAX_STAT);
-        self.energy = self.energy.saturating_sub(15);
+        self.energy = self.energy.saturating_sub(energy_cost);
         self.hunger = (self.hunger + 5).min(MAX_STAT);
 
         let activities = ["⚽", "🎾", "🛹", "🎮", "🏀"];
-        let activity = activities[rand::rng().random_range(0..activities.len())];
+        let activity = activities[self.rng.random_range(0..activities.len())];
+        self.last_played_at = Some(self.clock.now());
+        self.cared_for();
 
-        format!("{} played {} and is super happy!", self.name, activity)
-    }
-
-    pub fn toggle_sleep(&mut self) -> String {
-        if !self.is_alive {
-            return format!("💀 {} has passed away...", self.name);
+        ActionOutcome::Success {
+            message: format!("{} played {} and is super happy!", self.name, activity),
         }
+    }
 
-        self.is_sleeping = !self.is_sleeping;
+    /// Time left before `action` can be done again, or `None` if it can be done now. Only
+    /// feeding and playing have cooldowns.
+    pub fn cooldown_remaining(&self, action: Action) -> Option<Duration> {
+        let (last, minutes) = match action {
+            Action::Feed => (self.last_fed_at?, FEED_COOLDOWN_MINUTES),
+            Action::Play => (self.last_played_at?, PLAY_COOLDOWN_MINUTES),
+            _ => return None,
+        };
+
+        let remaining = last + Duration::minutes(minutes) - self.clock.now();
+        (remaining > Duration::zero()).then_some(remaining)
+    }
 
-        if self.is_sleeping {
-            format!("😴 {} has gone to sleep. Sweet dreams!", self.name)
-        } else {
-            format!("🌞 {} has woken up feeling refreshed!", self.name)
+    fn refuse(&self, reason: Refusal) -> ActionOutcome {
+        ActionOutcome::Refused {
+            name: self.name.clone(),
+            reason,
         }
     }
 
-    pub fn get_mood(&self) -> (&str, &str) {
+    /// A gentle happiness top-up. Unlike play it works on a hungry monster, but the bonus
+    /// recharges quadratically over `PET_RECHARGE_MINUTES`, so petting in a loop earns
+    /// almost nothing.
+    pub fn pet(&mut self) -> ActionOutcome {
         if !self.is_alive {
-            return ("💀", "Dead");
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
         }
 
         if self.is_sleeping {
-            return ("😴", "Sleeping");
+            return self.refuse(Refusal::Sleeping);
         }
 
-        let avg_stat = (self.happiness as u16
-            + (MAX_STAT.saturating_sub(self.hunger)) as u16
-            + self.health as u16
-            + self.energy as u16)
-            / 4;
+        let now = self.clock.now();
+        let bonus = pet_bonus(
+            self.last_petted_at
+                .map(|last| now.signed_duration_since(last)),
+        );
+        self.last_petted_at = Some(now);
 
-        let avg_stat = avg_stat as u8;
+        if bonus == 0 {
+            return ActionOutcome::Success {
+                message: format!("🤲 {} has had plenty of cuddles for now.", self.name),
+            };
+        }
 
-        match avg_stat {
-            90..=100 => ("😁", "Ecstatic"),
-            75..=89 => ("😊", "Happy"),
-            60..=74 => ("🙂", "Content"),
-            45..=59 => ("😐", "Okay"),
-            30..=44 => ("☹️", "Sad"),
-            15..=29 => ("😢", "Very Sad"),
-            _ => ("😵", "Critical"),
+        self.happiness = (self.happiness.saturating_add(bonus)).min(MAX_STAT);
+        self.cared_for();
+
+        ActionOutcome::Success {
+            message: format!(
+                "🥰 {} leans into the cuddle. (+{} happiness)",
+                self.name, bonus
+            ),
         }
     }
 
-    pub fn display(&self, stdout: &mut StdoutLock) -> Result<()> {
-        let (emoji, mood) = self.get_mood();
+    /// Washes the monster, raising cleanliness with a little happiness on top.
+    pub fn bath(&mut self) -> ActionOutcome {
+        if !self.is_alive {
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
+        }
+
+        if self.is_sleeping {
+            return self.refuse(Refusal::Sleeping);
+        }
 
-        write!(stdout, "╭─────────────────────────────────╮\r\n")?;
-        write!(stdout, "│      🐲  Monster Status  🐲     │\r\n")?;
-        write!(stdout, "╰─────────────────────────────────╯\r\n")?;
-        writeln!(stdout)?;
+        self.cleanliness = (self.cleanliness.saturating_add(BATH_CLEANLINESS_GAIN)).min(MAX_STAT);
+        self.happiness = (self.happiness.saturating_add(BATH_HAPPINESS_BONUS)).min(MAX_STAT);
+        self.cared_for();
+
+        ActionOutcome::Success {
+            message: format!("🛁 {} splashes around and comes out sparkling!", self.name),
+        }
+    }
 
+    /// Cures a cold and restores some health. A monster that isn't sick gets nothing but the
+    /// bad taste, and is less happy for it.
+    pub fn medicine(&mut self) -> ActionOutcome {
         if !self.is_alive {
-            write!(stdout, "        💀     💀\r\n")?;
-            write!(stdout, "          ╲   ╱\r\n")?;
-            write!(stdout, "           ╲ ╱\r\n")?;
-            write!(stdout, "         ───┴───\r\n")?;
-            write!(stdout, "        💀 R.I.P 💀\r\n")?;
-        } else if self.is_sleeping {
-            write!(stdout, "          zzZ\r\n")?;
-            write!(stdout, "        ╭─────╮\r\n")?;
-            write!(stdout, "       ╱  - -  ╲\r\n")?;
-            write!(stdout, "      ╱    ω    ╲\r\n")?;
-            write!(stdout, "     ╱___________╲\r\n")?;
-            write!(stdout, "        😴💤💤\r\n")?;
-        } else {
-            let face = match self.get_mood().0 {
-                "😁" => ("◕", "‿", "◕"),
-                "😊" => ("^", "‿", "^"),
-                "🙂" => ("•", "‿", "•"),
-                "😐" => ("•", "_", "•"),
-                "☹️" => ("•", "︵", "•"),
-                "😢" => ("╥", "﹏", "╥"),
-                "😵" => ("x", "_", "x"),
-                _ => ("•", "‿", "•"),
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
             };
+        }
 
-            write!(stdout, "        ╭─────╮\r\n")?;
-            write!(stdout, "       ╱  {} {}  ╲\r\n", face.0, face.2)?;
-            write!(stdout, "      ╱    {}    ╲\r\n", face.1)?;
-            write!(stdout, "     ╱___________╲\r\n")?;
-            write!(stdout, "        {}  {}\r\n", emoji, self.name)?;
-        }
-
-        writeln!(stdout)?;
-        write!(stdout, "📊 Stats:\r\n")?;
-
-        self.draw_status_bar(
-            stdout,
-            "🍽️  Hunger",
-            MAX_STAT - self.hunger,
-            Color::Green,
-            Color::Red,
-        )?;
-        self.draw_status_bar(
-            stdout,
-            "😊 Happiness",
-            self.happiness,
-            Color::Yellow,
-            Color::Grey,
-        )?;
-        self.draw_status_bar(stdout, "💖 Health", self.health, Color::Red, Color::DarkRed)?;
-        self.draw_status_bar(
-            stdout,
-            "⚡ Energy",
-            self.energy,
-            Color::Cyan,
-            Color::DarkCyan,
-        )?;
-
-        writeln!(stdout)?;
-        write!(stdout, "📈 Info:")?;
-        write!(stdout, "   Age: {} hours old\r\n", self.age)?;
-        write!(stdout, "   Mood: {}\r\n", mood)?;
-        write!(
-            stdout,
-            "   Status: {}\r\n",
-            if self.is_sleeping {
-                "😴 Sleeping"
-            } else {
-                "👁️ Awake"
-            }
-        )?;
+        if self.is_sleeping {
+            return self.refuse(Refusal::Sleeping);
+        }
 
-        if !self.is_alive {
-            stdout.queue(SetForegroundColor(Color::Red))?;
-            writeln!(stdout)?;
-            write!(
-                stdout,
-                "💀 Your pet has died. You can start over with a new pet.\r\n"
-            )?;
-            stdout.queue(ResetColor)?;
-        } else {
-            writeln!(stdout)?;
-            write!(
-                stdout,
-                "🎮 Commands: feed, play, sleep, status, interactive\r\n"
-            )?;
-
-            if self.hunger > 70 {
-                stdout.queue(SetForegroundColor(Color::Red))?;
-                write!(stdout, "⚠️  {} is very hungry!\r\n", self.name)?;
-                stdout.queue(ResetColor)?;
-            }
-            if self.happiness < 30 {
-                stdout.queue(SetForegroundColor(Color::Yellow))?;
-                write!(
-                    stdout,
-                    "⚠️  {} looks sad. Try playing with them!\r\n",
-                    self.name
-                )?;
-                stdout.queue(ResetColor)?;
-            }
-            if self.energy < 20 {
-                stdout.queue(SetForegroundColor(Color::Cyan))?;
-                write!(
-                    stdout,
-                    "⚠️  {} is exhausted. Let them sleep!\r\n",
-                    self.name
-                )?;
-                stdout.queue(ResetColor)?;
-            }
-            if self.health < 50 {
-                stdout.queue(SetForegroundColor(Color::Red))?;
-                write!(
-                    stdout,
-                    "⚠️  {} doesn't look well. Take better care!\r\n",
-                    self.name
-                )?;
-                stdout.queue(ResetColor)?;
-            }
+        if !self.sick {
+            self.happiness = self.happiness.saturating_sub(MEDICINE_HAPPINESS_COST);
+            return self.refuse(Refusal::NotSick);
         }
 
-        stdout.flush()?;
-        Ok(())
+        self.sick = false;
+        self.health = (self.health.saturating_add(MEDICINE_HEALTH_GAIN)).min(MAX_STAT);
+        self.cared_for();
+
+        ActionOutcome::Success {
+            message: format!(
+                "💊 {} took the medicine and feels better! (+{} health)",
+                self.name, MEDICINE_HEALTH_GAIN
+            ),
+        }
     }
 
-    fn draw_status_bar(
-        &self,
-        stdout: &mut StdoutLock,
-        label: &str,
-        value: u8,
-        good_color: Color,
-        bad_color: Color,
-    ) -> Result<()> {
-        let bar_width = 20;
-        let filled = (value as usize * bar_width) / MAX_STAT as usize;
-        let empty = bar_width - filled;
+    /// Gives the monster a new name, cleaned up like one typed at hatching. Refused, with
+    /// nothing changed, if the name holds control characters, is blank or is too long.
+    pub fn rename(&mut self, new_name: &str) -> ActionOutcome {
+        if new_name.chars().any(char::is_control) {
+            return self.refuse(Refusal::NameHasControlCharacters);
+        }
+
+        if new_name.trim().chars().count() > MAX_NAME_LENGTH {
+            return self.refuse(Refusal::NameTooLong(MAX_NAME_LENGTH));
+        }
 
-        write!(stdout, "   {}: [", label)?;
+        let Some(new_name) = sanitize_name(new_name) else {
+            return self.refuse(Refusal::NameBlank);
+        };
 
-        let color = if value > 60 { good_color } else { bad_color };
-        stdout.queue(SetForegroundColor(color))?;
+        let old_name = std::mem::replace(&mut self.name, new_name);
+        ActionOutcome::Success {
+            message: format!("✏️ {} is now known as {}!", old_name, self.name),
+        }
+    }
 
-        for _ in 0..filled {
-            write!(stdout, "█")?;
+    /// Puts the monster to bed if awake, wakes it if asleep. Used by the interactive toggle.
+    pub fn toggle_sleep(&mut self) -> ActionOutcome {
+        if self.is_sleeping {
+            self.wake()
+        } else {
+            self.sleep()
         }
+    }
 
-        stdout.queue(SetForegroundColor(Color::DarkGrey))?;
-        for _ in 0..empty {
-            write!(stdout, "░")?;
+    /// Puts the monster to bed. Refused, with nothing changed, if it is already asleep.
+    pub fn sleep(&mut self) -> ActionOutcome {
+        if !self.is_alive {
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
         }
 
-        stdout.queue(ResetColor)?;
-        write!(stdout, "] {}%\r\n", value)?;
+        if self.is_sleeping {
+            return self.refuse(Refusal::AlreadyAsleep);
+        }
 
-        Ok(())
+        self.is_sleeping = true;
+        ActionOutcome::Success {
+            message: format!("😴 {} has gone to sleep. Sweet dreams!", self.name),
+        }
+    }
+
+    /// Wakes the monster up. Refused, with nothing changed, if it is already awake.
+    pub fn wake(&mut self) -> ActionOutcome {
+        if !self.is_alive {
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
+        }
+
+        if !self.is_sleeping {
+            return self.refuse(Refusal::AlreadyAwake);
+        }
+
+        self.is_sleeping = false;
+        ActionOutcome::Success {
+            message: format!("🌞 {} has woken up feeling refreshed!", self.name),
+        }
+    }
+
+    pub fn train(&mut self, skill: Skill) -> ActionOutcome {
+        if !self.is_alive {
+            return ActionOutcome::Dead {
+                name: self.name.clone(),
+            };
+        }
+
+        if self.is_sleeping {
+            return self.refuse(Refusal::Sleeping);
+        }
+
+        let level = self.skill_level(skill);
+        if level >= MAX_SKILL_LEVEL {
+            return self.refuse(Refusal::AlreadyMastered(skill));
+        }
+
+        if self.energy < TRAINING_ENERGY_COST + 5 {
+            return self.refuse(Refusal::TooTiredToTrain);
+        }
+
+        if self.happiness < TRAINING_HAPPINESS_COST + 5 {
+            return self.refuse(Refusal::NotInTheMoodToTrain);
+        }
+
+        self.energy = self.energy.saturating_sub(TRAINING_ENERGY_COST);
+        self.happiness = self.happiness.saturating_sub(TRAINING_HAPPINESS_COST);
+        self.skills.insert(skill, level + 1);
+        self.cared_for();
+
+        ActionOutcome::Success {
+            message: format!(
+                "{} {} trained {} and reached level {}!",
+                skill.emoji(),
+                self.name,
+                skill.name(),
+                level + 1
+            ),
+        }
+    }
+
+    pub fn skill_level(&self, skill: Skill) -> u8 {
+        self.skills.get(&skill).copied().unwrap_or(0)
+    }
+
+    pub fn skills_summary(&self) -> String {
+        Skill::ALL
+            .iter()
+            .map(|skill| {
+                format!(
+                    "{} {}/{}",
+                    skill.name(),
+                    self.skill_level(*skill),
+                    MAX_SKILL_LEVEL
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn weight_category(&self) -> WeightCategory {
+        if self.weight < UNDERWEIGHT_BELOW {
+            WeightCategory::Underweight
+        } else if self.weight > OVERWEIGHT_ABOVE {
+            WeightCategory::Overweight
+        } else {
+            WeightCategory::Healthy
+        }
+    }
+
+    pub fn is_hungry(&self) -> bool {
+        self.hunger > self.thresholds.hungry
+    }
+
+    pub fn is_starving(&self) -> bool {
+        self.hunger > self.thresholds.starving
+    }
+
+    pub fn is_sad(&self) -> bool {
+        self.happiness < self.thresholds.sad
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.energy < self.thresholds.exhausted
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.cleanliness < self.thresholds.dirty
+    }
+
+    pub fn is_unwell(&self) -> bool {
+        self.health < self.thresholds.unwell
+    }
+
+    /// Alive but close to dying: starving or with critically low health.
+    pub fn is_critical(&self) -> bool {
+        self.is_alive && (self.is_starving() || self.health < self.thresholds.critical_health)
+    }
+
+    /// What the monster wants done, most urgent first. Each need is ranked by how far its
+    /// stat is past the warning threshold, and starving beats everything.
+    pub fn needs(&self) -> Vec<Need> {
+        if !self.is_alive {
+            return Vec::new();
+        }
+
+        let mut needs: Vec<(u8, Need)> = Vec::new();
+
+        if self.is_hungry() {
+            let urgency = self.hunger - self.thresholds.hungry;
+            let urgency = if self.is_starving() {
+                urgency.saturating_add(MAX_STAT)
+            } else {
+                urgency
+            };
+            needs.push((urgency, Need::Feed));
+        }
+
+        if self.is_sleeping {
+            if self.energy >= MAX_STAT {
+                needs.push((0, Need::Wake));
+            }
+        } else {
+            if self.is_exhausted() {
+                needs.push((self.thresholds.exhausted - self.energy, Need::Rest));
+            }
+            if self.is_sad() {
+                needs.push((self.thresholds.sad - self.happiness, Need::Play));
+            }
+        }
+
+        needs.sort_by_key(|(urgency, _)| Reverse(*urgency));
+        needs.into_iter().map(|(_, need)| need).collect()
+    }
+
+    /// The most severe condition that currently needs the player's attention.
+    pub fn current_alert(&self) -> Option<Alert> {
+        if !self.is_alive {
+            Some(match self.cause_of_death {
+                Some(CauseOfDeath::OldAge) => Alert::PassedAway,
+                _ => Alert::Dead,
+            })
+        } else if self.starving_since.is_some() {
+            Some(Alert::Neglected)
+        } else if self.is_starving() {
+            Some(Alert::Starving)
+        } else if self.health < self.thresholds.critical_health {
+            Some(Alert::LowHealth)
+        } else if self.sick {
+            Some(Alert::Sick)
+        } else {
+            None
+        }
+    }
+
+    /// The process exit code for `status`: 0 alive and out of danger, 1 dead, 2 critical
+    /// (hunger above 80, energy below 10 or health below `Thresholds::critical_health`).
+    pub fn health_exit_code(&self) -> ExitCode {
+        if !self.is_alive {
+            ExitCode::from(1)
+        } else if self.is_neglected(Neglect::Hunger)
+            || self.is_neglected(Neglect::Energy)
+            || self.health < self.thresholds.critical_health
+        {
+            ExitCode::from(2)
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+
+    pub fn life_stage(&self) -> LifeStage {
+        LifeStage::from_age(self.age)
+    }
+
+    pub fn health_band(&self) -> HealthBand {
+        if !self.is_alive || self.is_critical() {
+            HealthBand::Critical
+        } else if self.is_hungry()
+            || self.is_sad()
+            || self.is_exhausted()
+            || self.is_unwell()
+            || self.sick
+        {
+            HealthBand::Warning
+        } else {
+            HealthBand::Healthy
+        }
+    }
+
+    /// A short sentence built from the individual stats, e.g. "Fluffy is well-fed but lonely."
+    pub fn describe(&self) -> String {
+        if !self.is_alive {
+            return format!("{} is no longer with us.", self.name);
+        }
+
+        if self.is_sleeping {
+            return format!("{} is fast asleep.", self.name);
+        }
+
+        let mut good = Vec::new();
+        let mut bad = Vec::new();
+
+        if self.is_starving() {
+            bad.push("starving");
+        } else if self.is_hungry() {
+            bad.push("hungry");
+        } else if self.hunger < 30 {
+            good.push("well-fed");
+        }
+
+        if self.is_sad() {
+            bad.push("lonely");
+        } else if self.happiness > 80 {
+            good.push("cheerful");
+        }
+
+        if self.is_exhausted() {
+            bad.push("exhausted");
+        } else if self.energy > 80 {
+            good.push("energetic");
+        }
+
+        if self.sick {
+            bad.push("sniffly");
+        } else if self.is_unwell() {
+            bad.push("poorly");
+        }
+
+        if bad.len() >= 2 && self.happiness < 50 {
+            bad.push("grumpy");
+        }
+
+        let feeling = match (good.is_empty(), bad.is_empty()) {
+            (true, true) => "doing just fine".to_string(),
+            (false, true) => join_words(&good),
+            (true, false) => join_words(&bad),
+            (false, false) => format!("{} but {}", join_words(&good), join_words(&bad)),
+        };
+
+        format!("{} is {}.", self.name, feeling)
+    }
+
+    /// How well looked after the monster is overall: the average of its stats, with hunger
+    /// counted inverted so that higher is always better.
+    pub fn care_score(&self) -> u8 {
+        let total = self.happiness as u16
+            + (MAX_STAT.saturating_sub(self.hunger)) as u16
+            + self.health as u16
+            + self.energy as u16
+            + self.cleanliness as u16;
+
+        (total / 5) as u8
+    }
+
+    pub fn get_mood(&self) -> (&str, &str) {
+        if !self.is_alive {
+            return ("💀", "Dead");
+        }
+
+        if self.is_sleeping {
+            return ("😴", "Sleeping");
+        }
+
+        match self.care_score() {
+            90..=100 => ("😁", "Ecstatic"),
+            75..=89 => ("😊", "Happy"),
+            60..=74 => ("🙂", "Content"),
+            45..=59 => ("😐", "Okay"),
+            30..=44 => ("☹️", "Sad"),
+            15..=29 => ("😢", "Very Sad"),
+            _ => ("😵", "Critical"),
+        }
+    }
+
+    /// A line for the current mood, picked by index so the same `pick` always gives the
+    /// same line. `None` for a dead monster.
+    pub fn quip(&self, pick: usize) -> Option<&'static str> {
+        let (emoji, _) = self.get_mood();
+        let pool = quip::pool(emoji);
+        (!pool.is_empty()).then(|| pool[pick % pool.len()])
+    }
+
+    /// A random line for the current mood, drawn from the monster's own (seedable) RNG.
+    /// Empty for a dead monster.
+    pub fn say(&mut self) -> String {
+        let pick = self.rng.random_range(0..usize::MAX);
+        self.quip(pick).unwrap_or_default().to_string()
+    }
+
+    /// A colorless one-liner such as `Fluffy 🙂 ❤100`, meant for shell prompts.
+    pub fn summary(&self) -> String {
+        let (emoji, _) = self.get_mood();
+        format!("{} {} ❤{}", self.name, emoji, self.health)
+    }
+
+    /// One line of space-separated `key=value` pairs for monitoring scripts. Values with
+    /// spaces are double-quoted; booleans are `0`/`1`.
+    pub fn stats_line(&self) -> String {
+        let name = if self.name.contains(char::is_whitespace) {
+            format!("\"{}\"", self.name.replace('"', "'"))
+        } else {
+            self.name.clone()
+        };
+
+        format!(
+            "name={} health={} hunger={} happiness={} energy={} weight={} age={} alive={} sleeping={} sick={} band={}",
+            name,
+            self.health,
+            self.hunger,
+            self.happiness,
+            self.energy,
+            self.weight,
+            self.age,
+            u8::from(self.is_alive),
+            u8::from(self.is_sleeping),
+            u8::from(self.sick),
+            self.health_band().label()
+        )
+    }
+
+    /// The status as a Markdown heading and table, ready to paste into a chat or issue.
+    pub fn to_markdown(&self) -> String {
+        let (emoji, mood) = self.get_mood();
+        let rows = [
+            ("Species", self.species.to_string()),
+            ("Mood", format!("{} {}", emoji, mood)),
+            ("Age", format!("{} hours", self.age)),
+            ("Hunger", format!("{}%", self.hunger)),
+            ("Happiness", format!("{}%", self.happiness)),
+            ("Energy", format!("{}%", self.energy)),
+            ("Health", format!("{}%", self.health)),
+            ("Bond", format!("{}%", self.affection)),
+            (
+                "Weight",
+                format!("{} ({})", self.weight, self.weight_category().label()),
+            ),
+            ("Coins", self.coins.to_string()),
+        ];
+
+        let mut markdown = format!(
+            "### {}\n\n| Stat | Value |\n| --- | --- |\n",
+            escape_markdown(&self.name)
+        );
+        for (label, value) in rows {
+            markdown.push_str(&format!("| {} | {} |\n", label, escape_markdown(&value)));
+        }
+
+        markdown
+    }
+}
+
+/// Reads a name from stdin. Invalid UTF-8 is replaced rather than rejected, and a failed read
+/// gives `None` so hatching carries on with the default name.
+fn read_name() -> Option<String> {
+    prompt::read_line().and_then(|raw| sanitize_name(&raw))
+}
+
+/// Turns raw input into a usable name: surrounding whitespace (including a Windows `\r`) is
+/// trimmed, control characters are dropped and the result is cut to `MAX_NAME_LENGTH`
+/// characters. Returns `None` if nothing is left.
+pub fn sanitize_name(raw: &str) -> Option<String> {
+    let cleaned: String = raw.chars().filter(|c| !c.is_control()).collect();
+    let name: String = cleaned.trim().chars().take(MAX_NAME_LENGTH).collect();
+    let name = name.trim_end();
+
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// A short wait rounded up to whole minutes, e.g. "4 minutes".
+pub fn format_wait(wait: Duration) -> String {
+    let minutes = (wait.num_seconds() + 59) / 60;
+    format!("{} minute{}", minutes, if minutes == 1 { "" } else { "s" })
+}
+
+/// Happiness earned by a pet given the time since the previous one (`None` if never petted).
+fn pet_bonus(since_last: Option<Duration>) -> u8 {
+    let Some(since_last) = since_last else {
+        return PET_MAX_BONUS;
+    };
+
+    let minutes = since_last.num_minutes().clamp(0, PET_RECHARGE_MINUTES);
+    (PET_MAX_BONUS as i64 * minutes * minutes / (PET_RECHARGE_MINUTES * PET_RECHARGE_MINUTES)) as u8
+}
+
+/// Happiness lost to boredom over `hours` of sleep starting at `age`. Counted against age so
+/// hour-by-hour updates lose a point every other hour instead of rounding down to nothing.
+fn sleep_boredom(age: u32, hours: u32) -> u8 {
+    let bored_until = |age: u32| age as u64 * STAT_DECAY_RATE as u64 / 4;
+    let boredom = bored_until(age.saturating_add(hours)) - bored_until(age);
+    boredom.min(MAX_STAT as u64) as u8
+}
+
+fn decay_stat(value: u8, amount: u8, floor: u8) -> u8 {
+    value.saturating_sub(amount).max(floor)
+}
+
+/// Backslash-escapes the characters Markdown would otherwise treat as formatting, including
+/// the `|` that would split a table cell.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '|' | '[' | ']' | '<' | '>' | '#' | '~'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Draws the egg-cracking frames over one another with a short pause between them.
+fn play_hatching(out: &mut impl Write) -> Result<()> {
+    for (index, frame) in sprite::HATCHING.iter().enumerate() {
+        if index > 0 {
+            out.queue(MoveUp(frame.len() as u16))?;
+        }
+        for line in frame {
+            writeln!(out, "{}", line)?;
+        }
+        out.flush()?;
+        thread::sleep(HATCHING_FRAME);
+    }
+    writeln!(out)?;
+
+    Ok(())
+}
+
+/// Reads and parses a saved monster, in whichever format the file turns out to be.
+fn read_state(path: &Path) -> Result<(Monster, SaveFormat)> {
+    let mut file = File::open(path)
+        .with_context(|| format!("Failed to open state file {}", path.display()))?;
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .with_context(|| "Failed to read state file")?;
+
+    let format = SaveFormat::detect(path, &content);
+    let monster = format
+        .deserialize(&content)
+        .with_context(|| "Failed to parse state file")?;
+
+    Ok((monster, format))
+}
+
+fn join_words(words: &[&str]) -> String {
+    match words {
+        [] => String::new(),
+        [word] => word.to_string(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::app_state::clock::MockClock;
+
+    /// No random life event fires for this seed in the first 80 simulated hours of a healthy
+    /// monster, so decay can be checked exactly.
+    const SEED: u64 = 92;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap()
+    }
+
+    /// A fresh Blob on a mock clock set to `start()`.
+    fn hatch() -> (Monster, Arc<MockClock>) {
+        let clock = Arc::new(MockClock::new(start()));
+        let mut monster = Monster::new("Testy".to_string(), Species::Blob)
+            .with_clock(clock.clone())
+            .with_seed(SEED);
+        monster.updated_at = start();
+        (monster, clock)
+    }
+
+    #[test]
+    fn advancing_the_clock_applies_decay() {
+        let (mut monster, clock) = hatch();
+
+        clock.advance(Duration::hours(3));
+        let passage = monster.update_from_time_passage();
+
+        assert!(passage.events.is_empty());
+        assert_eq!(monster.hunger, 56);
+        assert_eq!(monster.happiness, 67);
+        assert_eq!(monster.energy, 74);
+        assert_eq!(monster.age, 3);
+        assert_eq!(monster.updated_at, clock.now());
+    }
+
+    #[test]
+    fn a_clock_that_stands_still_changes_nothing() {
+        let (mut monster, _clock) = hatch();
+
+        let passage = monster.update_from_time_passage();
+
+        assert!(passage.is_empty());
+        assert_eq!(monster.hunger, 50);
+        assert_eq!(monster.age, 0);
+        assert_eq!(monster.updated_at, start());
+    }
+
+    #[test]
+    fn half_hour_updates_add_up_to_decay() {
+        let (mut monster, clock) = hatch();
+
+        clock.advance(Duration::minutes(30));
+        monster.update_from_time_passage();
+        assert_eq!(monster.hunger, 50);
+        assert_eq!(monster.updated_at, start());
+
+        clock.advance(Duration::minutes(30));
+        let passage = monster.update_from_time_passage();
+        assert_eq!(passage.hours, 1);
+        assert_eq!(monster.hunger, 52);
+        assert_eq!(monster.updated_at, start() + Duration::hours(1));
+    }
+
+    #[test]
+    fn warning_predicates_trip_just_past_their_thresholds() {
+        let (mut monster, _clock) = hatch();
+
+        monster.hunger = 70;
+        assert!(!monster.is_hungry());
+        monster.hunger = 71;
+        assert!(monster.is_hungry());
+
+        monster.happiness = 30;
+        assert!(!monster.is_sad());
+        monster.happiness = 29;
+        assert!(monster.is_sad());
+
+        monster.energy = 20;
+        assert!(!monster.is_exhausted());
+        monster.energy = 19;
+        assert!(monster.is_exhausted());
+    }
+
+    #[test]
+    fn critical_means_starving_or_critically_low_health() {
+        let (mut monster, _clock) = hatch();
+
+        monster.hunger = 90;
+        assert!(!monster.is_critical());
+        monster.hunger = 91;
+        assert!(monster.is_critical());
+
+        monster.hunger = 50;
+        monster.health = 20;
+        assert!(!monster.is_critical());
+        monster.health = 19;
+        assert!(monster.is_critical());
+
+        monster.is_alive = false;
+        assert!(!monster.is_critical());
+    }
+
+    #[test]
+    fn a_5000_hour_gap_ages_in_full_but_decays_like_the_cap() {
+        let (mut capped, clock) = hatch();
+        clock.advance(Duration::hours(DEFAULT_MAX_CATCH_UP_HOURS as i64));
+        capped.update_from_time_passage();
+
+        let (mut monster, clock) = hatch();
+        clock.advance(Duration::hours(5000));
+        let passage = monster.update_from_time_passage();
+
+        assert_eq!(passage.hours, 5000);
+        assert_eq!(monster.age, 5000);
+        assert_eq!(monster.updated_at, clock.now());
+        assert_eq!(
+            (
+                monster.hunger,
+                monster.happiness,
+                monster.energy,
+                monster.health
+            ),
+            (
+                capped.hunger,
+                capped.happiness,
+                capped.energy,
+                capped.health
+            )
+        );
+        assert!(passage.died);
+    }
+
+    /// Testy with the given hunger, happiness and energy, and everything else left alone.
+    fn feeling(hunger: u8, happiness: u8, energy: u8) -> String {
+        let (mut monster, _clock) = hatch();
+        monster.hunger = hunger;
+        monster.happiness = happiness;
+        monster.energy = energy;
+        monster.describe()
+    }
+
+    #[test]
+    fn describe_follows_the_individual_stats() {
+        assert_eq!(feeling(50, 70, 50), "Testy is doing just fine.");
+        assert_eq!(
+            feeling(20, 90, 90),
+            "Testy is well-fed, cheerful and energetic."
+        );
+        assert_eq!(feeling(20, 20, 50), "Testy is well-fed but lonely.");
+        assert_eq!(
+            feeling(75, 40, 10),
+            "Testy is hungry, exhausted and grumpy."
+        );
+        assert_eq!(
+            feeling(95, 90, 90),
+            "Testy is cheerful and energetic but starving."
+        );
+    }
+
+    #[test]
+    fn describe_puts_sleep_and_death_first() {
+        let (mut monster, _clock) = hatch();
+        monster.hunger = 95;
+
+        monster.is_sleeping = true;
+        assert_eq!(monster.describe(), "Testy is fast asleep.");
+
+        monster.is_alive = false;
+        assert_eq!(monster.describe(), "Testy is no longer with us.");
+    }
+
+    /// A save from before `schema_version`, with only the fields the first release wrote.
+    const V0_SAVE: &str = r#"{
+        "name": "Oldie",
+        "hunger": 40,
+        "happiness": 120,
+        "energy": 60,
+        "health": 90,
+        "age": 12,
+        "is_sleeping": false,
+        "is_alive": true,
+        "updated_at": "2025-06-01T08:00:00Z"
+    }"#;
+
+    #[test]
+    fn a_v0_save_loads_and_migrates() {
+        let mut monster: Monster = serde_json::from_str(V0_SAVE).unwrap();
+        assert_eq!(monster.schema_version, 0);
+
+        monster.migrate();
+
+        assert_eq!(monster.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(monster.name, "Oldie");
+        assert_eq!(monster.species, Species::Blob);
+        assert_eq!(monster.happiness, MAX_STAT);
+        assert_eq!(monster.cleanliness, MAX_STAT);
+        assert_eq!(monster.weight, Species::Blob.baseline().weight);
+        assert!(monster.events.is_empty());
+        assert!(monster.is_alive);
+    }
+
+    #[test]
+    fn migrating_a_dead_v1_save_records_neglect() {
+        let mut monster: Monster = serde_json::from_str(V0_SAVE).unwrap();
+        monster.schema_version = 1;
+        monster.is_alive = false;
+
+        monster.migrate();
+
+        assert_eq!(monster.cause_of_death, Some(CauseOfDeath::Neglect));
+    }
+
+    #[test]
+    fn decay_stops_at_its_floor() {
+        assert_eq!(decay_stat(50, 10, HAPPINESS_DECAY_FLOOR), 40);
+        assert_eq!(
+            decay_stat(5, 10, HAPPINESS_DECAY_FLOOR),
+            HAPPINESS_DECAY_FLOOR
+        );
+        assert_eq!(decay_stat(5, 10, ENERGY_DECAY_FLOOR), 0);
+    }
+
+    #[test]
+    fn time_passage_floors_happiness_but_lets_energy_and_hunger_run_out() {
+        let (mut monster, clock) = hatch();
+        monster.hunger = 95;
+        monster.happiness = 5;
+        monster.energy = 5;
+
+        clock.advance(Duration::hours(10));
+        monster.update_from_time_passage();
+
+        assert_eq!(monster.happiness, HAPPINESS_DECAY_FLOOR);
+        assert_eq!(monster.energy, 0);
+        assert_eq!(monster.hunger, MAX_STAT);
+    }
+
+    #[test]
+    fn sleep_slows_happiness_loss_without_stopping_it() {
+        let (mut awake, clock) = hatch();
+        awake.energy = 0;
+        let (mut asleep, _) = hatch();
+        asleep.energy = 0;
+        asleep.is_sleeping = true;
+
+        clock.advance(Duration::hours(12));
+        awake.update_from_time_passage();
+        asleep = asleep.with_clock(clock.clone());
+        asleep.update_from_time_passage();
+
+        assert!(asleep.is_sleeping);
+        assert_eq!(awake.happiness, 58);
+        assert_eq!(asleep.happiness, 64);
+        assert_eq!(asleep.hunger, 62);
+        assert_eq!(awake.hunger, 74);
+        assert_eq!(asleep.energy, 60);
+    }
+
+    #[test]
+    fn regular_care_grows_affection() {
+        let (mut monster, clock) = hatch();
+
+        monster.pet();
+        assert_eq!(
+            monster.affection, 0,
+            "the first interaction only starts the cadence"
+        );
+
+        clock.advance(Duration::hours(2));
+        monster.pet();
+        assert_eq!(monster.affection, AFFECTION_GAIN);
+
+        clock.advance(Duration::minutes(10));
+        monster.pet();
+        assert_eq!(
+            monster.affection, AFFECTION_GAIN,
+            "care too soon doesn't count"
+        );
+
+        clock.advance(Duration::hours(2));
+        monster.pet();
+        assert_eq!(monster.affection, 2 * AFFECTION_GAIN);
+    }
+
+    #[test]
+    fn affection_fades_once_care_lapses() {
+        let (mut cared_for, clock) = hatch();
+        cared_for.affection = 20;
+        cared_for.last_cared_at = Some(start() - Duration::hours(CARE_CADENCE_HOURS - 6));
+        let mut neglected = cared_for.clone();
+        neglected.last_cared_at = Some(start() - Duration::hours(CARE_CADENCE_HOURS));
+
+        clock.advance(Duration::hours(6));
+        cared_for.update_from_time_passage();
+        neglected.update_from_time_passage();
+
+        assert_eq!(cared_for.affection, 20);
+        assert_eq!(neglected.affection, 14);
+    }
+
+    #[test]
+    fn petting_again_soon_gives_less() {
+        let minutes = |m| pet_bonus(Some(Duration::minutes(m)));
+
+        assert_eq!(pet_bonus(None), PET_MAX_BONUS);
+        assert_eq!(minutes(PET_RECHARGE_MINUTES), PET_MAX_BONUS);
+        assert_eq!(minutes(3 * PET_RECHARGE_MINUTES), PET_MAX_BONUS);
+        assert_eq!(minutes(15), 1);
+        assert_eq!(minutes(0), 0);
+
+        let curve: Vec<u8> = (0..=PET_RECHARGE_MINUTES).map(minutes).collect();
+        assert!(curve.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn a_second_pet_straight_away_does_nothing() {
+        let (mut monster, _clock) = hatch();
+        monster.happiness = 50;
+
+        monster.pet();
+        assert_eq!(monster.happiness, 50 + PET_MAX_BONUS);
+        monster.pet();
+        assert_eq!(monster.happiness, 50 + PET_MAX_BONUS);
+    }
+
+    #[test]
+    fn petting_works_on_a_hungry_monster() {
+        let (mut monster, _clock) = hatch();
+        monster.hunger = 75;
+        monster.happiness = 50;
+
+        monster.pet();
+        assert_eq!(monster.happiness, 50 + PET_MAX_BONUS);
+    }
+
+    #[test]
+    fn time_passage_reports_what_changed() {
+        let (mut monster, clock) = hatch();
+
+        clock.advance(Duration::hours(3));
+        let passage = monster.update_from_time_passage();
+
+        assert_eq!(passage.hours, 3);
+        assert_eq!(passage.hunger, 6);
+        assert_eq!(passage.happiness, -3);
+        assert_eq!(passage.energy, -6);
+        assert_eq!(passage.health, 0);
+        assert!(!passage.died);
+        assert_eq!(
+            passage.summary("Testy"),
+            "While you were away (3h): hunger +6, happiness -3, energy -6"
+        );
+    }
+
+    #[test]
+    fn a_lower_catch_up_cap_bounds_decay() {
+        let (mut monster, clock) = hatch();
+        monster.configure(&Config {
+            max_catch_up_hours: Some(3),
+            ..Config::default()
+        });
+
+        clock.advance(Duration::hours(10));
+        monster.update_from_time_passage();
+
+        assert_eq!(monster.age, 10);
+        assert_eq!(monster.hunger, 56);
+        assert_eq!(monster.happiness, 67);
+        assert_eq!(monster.energy, 74);
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, d).unwrap()
+    }
+
+    #[test]
+    fn caring_on_consecutive_days_builds_a_streak() {
+        let (mut monster, _clock) = hatch();
+        monster.happiness = 50;
+
+        monster.extend_streak(day(5));
+        assert_eq!(monster.care_streak, 1);
+        assert_eq!(monster.happiness, 50);
+
+        monster.extend_streak(day(6));
+        monster.extend_streak(day(7));
+        assert_eq!(monster.care_streak, 3);
+        assert_eq!(monster.happiness, 50 + 1 + 2);
+    }
+
+    #[test]
+    fn more_care_the_same_day_changes_nothing() {
+        let (mut monster, _clock) = hatch();
+        monster.extend_streak(day(5));
+        monster.extend_streak(day(6));
+        let happiness = monster.happiness;
+
+        monster.extend_streak(day(6));
+        monster.extend_streak(day(6));
+        assert_eq!(monster.care_streak, 2);
+        assert_eq!(monster.happiness, happiness);
+    }
+
+    #[test]
+    fn skipping_a_day_starts_the_streak_over() {
+        let (mut monster, _clock) = hatch();
+        monster.extend_streak(day(5));
+        monster.extend_streak(day(6));
+        let happiness = monster.happiness;
+
+        monster.extend_streak(day(8));
+        assert_eq!(monster.care_streak, 1);
+        assert_eq!(monster.happiness, happiness);
+        assert_eq!(monster.last_care_day, Some(day(8)));
+    }
+
+    #[test]
+    fn the_streak_bonus_is_capped() {
+        let (mut monster, _clock) = hatch();
+        monster.happiness = 10;
+        monster.care_streak = 30;
+        monster.last_care_day = Some(day(5));
+
+        monster.extend_streak(day(6));
+        assert_eq!(monster.happiness, 10 + MAX_STREAK_BONUS);
+    }
+
+    #[test]
+    fn a_toml_save_reads_back_the_same() {
+        let (mut monster, _clock) = hatch();
+        monster.hunger = 33;
+        monster.last_cared_at = Some(start() - Duration::minutes(90));
+        monster.last_care_day = Some(NaiveDate::from_ymd_opt(2026, 1, 4).unwrap());
+
+        let content = SaveFormat::Toml.serialize(&monster).unwrap();
+        assert!(content.contains("updated_at = "));
+        let path = env::temp_dir().join(format!("mons-box-toml-{}.toml", std::process::id()));
+        fs::write(&path, &content).unwrap();
+        let read = read_state(&path);
+        fs::remove_file(&path).unwrap();
+
+        let (loaded, format) = read.unwrap();
+        assert_eq!(format, SaveFormat::Toml);
+        assert_eq!(loaded.name, monster.name);
+        assert_eq!(loaded.species, monster.species);
+        assert_eq!(loaded.hunger, 33);
+        assert_eq!(loaded.updated_at, start());
+        assert_eq!(loaded.last_cared_at, monster.last_cared_at);
+        assert_eq!(loaded.last_care_day, monster.last_care_day);
+        assert_eq!(loaded.died_at, None);
+    }
+
+    #[test]
+    fn sleep_puts_an_awake_monster_to_bed() {
+        let (mut monster, _clock) = hatch();
+
+        assert!(matches!(monster.sleep(), ActionOutcome::Success { .. }));
+        assert!(monster.is_sleeping);
+    }
+
+    #[test]
+    fn sleep_leaves_a_sleeping_monster_alone() {
+        let (mut monster, _clock) = hatch();
+        monster.is_sleeping = true;
+
+        assert!(matches!(
+            monster.sleep(),
+            ActionOutcome::Refused {
+                reason: Refusal::AlreadyAsleep,
+                ..
+            }
+        ));
+        assert!(monster.is_sleeping);
+    }
+
+    #[test]
+    fn wake_gets_a_sleeping_monster_up() {
+        let (mut monster, _clock) = hatch();
+        monster.is_sleeping = true;
+
+        assert!(matches!(monster.wake(), ActionOutcome::Success { .. }));
+        assert!(!monster.is_sleeping);
+    }
+
+    #[test]
+    fn wake_leaves_an_awake_monster_alone() {
+        let (mut monster, _clock) = hatch();
+
+        assert!(matches!(
+            monster.wake(),
+            ActionOutcome::Refused {
+                reason: Refusal::AlreadyAwake,
+                ..
+            }
+        ));
+        assert!(!monster.is_sleeping);
+    }
+
+    #[test]
+    fn markdown_status_is_a_table() {
+        let (mut monster, _clock) = hatch();
+        monster.age = 30;
+        monster.coins = 12;
+
+        assert_eq!(
+            monster.to_markdown(),
+            "### Testy\n\
+             \n\
+             | Stat | Value |\n\
+             | --- | --- |\n\
+             | Species | Blob |\n\
+             | Mood | 🙂 Content |\n\
+             | Age | 30 hours |\n\
+             | Hunger | 50% |\n\
+             | Happiness | 70% |\n\
+             | Energy | 80% |\n\
+             | Health | 100% |\n\
+             | Bond | 0% |\n\
+             | Weight | 50 (⚖️ Healthy) |\n\
+             | Coins | 12 |\n"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_the_name() {
+        let (mut monster, _clock) = hatch();
+        monster.name = "*Mr|Bob*".to_string();
+
+        assert!(monster.to_markdown().starts_with("### \\*Mr\\|Bob\\*\n"));
+        assert_eq!(escape_markdown("a_b [c]"), "a\\_b \\[c\\]");
+    }
+
+    #[test]
+    fn a_hunger_strike_hurts_more_the_longer_it_lasts() {
+        let (mut monster, _clock) = hatch();
+        monster.hunger = MAX_STAT;
+        monster.happiness = 90;
+        let at = |hours| start() + Duration::hours(hours);
+
+        monster.hunger_strike(at(0), 1);
+        assert_eq!(monster.starving_since, Some(start()));
+        assert_eq!((monster.health, monster.happiness), (100, 90));
+
+        monster.hunger_strike(at(1), 1);
+        assert_eq!(monster.health, 99);
+        assert_eq!(monster.happiness, 90 - STRIKE_HAPPINESS_DROP);
+
+        monster.hunger_strike(at(3), 1);
+        assert_eq!(monster.health, 96);
+
+        monster.hunger_strike(at(20), 1);
+        assert_eq!(monster.health, 96 - MAX_STRIKE_ESCALATION as u8);
+        assert_eq!(monster.current_alert(), Some(Alert::Neglected));
+    }
+
+    #[test]
+    fn feeding_ends_a_hunger_strike() {
+        let (mut monster, clock) = hatch();
+        monster.hunger = MAX_STAT;
+        monster.hunger_strike(start() - Duration::hours(5), 1);
+
+        assert!(matches!(monster.feed(), ActionOutcome::Success { .. }));
+        assert_eq!(monster.starving_since, None);
+
+        let health = monster.health;
+        clock.advance(Duration::hours(1));
+        monster.hunger_strike(clock.now(), 1);
+        assert_eq!(monster.health, health);
+    }
+
+    #[test]
+    fn a_dirty_monster_gets_less_out_of_food() {
+        let (mut clean, _clock) = hatch();
+        clean.health = 50;
+        let mut dirty = clean.clone();
+        dirty.cleanliness = 5;
+        assert!(dirty.is_dirty());
+
+        clean.feed_with(Food::Carrot);
+        dirty.feed_with(Food::Carrot);
+
+        assert_eq!(clean.health, 60);
+        assert_eq!(dirty.health, 55);
+    }
+
+    #[test]
+    fn a_dirty_monster_loses_health_faster() {
+        let (mut clean, clock) = hatch();
+        let mut dirty = clean.clone();
+        dirty.cleanliness = 5;
+
+        clock.advance(Duration::hours(3));
+        clean.update_from_time_passage();
+        dirty.update_from_time_passage();
+
+        assert_eq!(clean.health, 100);
+        assert!(dirty.health < clean.health);
+    }
+
+    /// A hungry Testy that has been in the danger zone since `start()`.
+    fn starving_with_grace(hours: u32) -> (Monster, Arc<MockClock>) {
+        let (mut monster, clock) = hatch();
+        monster.configure(&Config {
+            neglect_grace_hours: Some(hours),
+            ..Config::default()
+        });
+        monster.hunger = 85;
+        (monster, clock)
+    }
+
+    #[test]
+    fn no_grace_hurts_straight_away() {
+        let (mut monster, clock) = starving_with_grace(0);
+
+        clock.advance(Duration::hours(1));
+        monster.update_from_time_passage();
+        assert!(monster.health < 100);
+    }
+
+    #[test]
+    fn neglect_is_forgiven_within_the_grace_window() {
+        let (mut monster, clock) = starving_with_grace(4);
+
+        for _ in 0..3 {
+            clock.advance(Duration::hours(1));
+            monster.update_from_time_passage();
+        }
+        assert_eq!(monster.health, 100);
+
+        clock.advance(Duration::hours(2));
+        monster.update_from_time_passage();
+        assert!(monster.health < 100);
+    }
+
+    #[test]
+    fn a_rename_survives_save_and_load() {
+        let (mut monster, _clock) = hatch();
+
+        assert_eq!(
+            monster.rename("  Rex  ").to_string(),
+            "✏️ Testy is now known as Rex!"
+        );
+
+        let path = env::temp_dir().join(format!("mons-box-rename-{}.json", std::process::id()));
+        let content = SaveFormat::Json.serialize(&monster).unwrap();
+        storage::write_atomic(&path, content.as_bytes()).unwrap();
+        let loaded = Monster::load_from(&path, &Config::default());
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap().unwrap().name, "Rex");
+    }
+
+    #[test]
+    fn a_bad_name_is_refused() {
+        let (mut monster, _clock) = hatch();
+        let refusal = |outcome| match outcome {
+            ActionOutcome::Refused { reason, .. } => Some(reason),
+            _ => None,
+        };
+
+        assert_eq!(refusal(monster.rename("   ")), Some(Refusal::NameBlank));
+        assert_eq!(
+            refusal(monster.rename("Rex\u{7}")),
+            Some(Refusal::NameHasControlCharacters)
+        );
+        assert_eq!(
+            refusal(monster.rename(&"x".repeat(MAX_NAME_LENGTH + 1))),
+            Some(Refusal::NameTooLong(MAX_NAME_LENGTH))
+        );
+        assert_eq!(monster.name, "Testy");
+    }
+
+    #[test]
+    fn the_same_seed_picks_the_same_food_and_game() {
+        let seeded = |seed| {
+            let (monster, clock) = hatch();
+            (monster.with_seed(seed), clock)
+        };
+        let (mut a, clock_a) = seeded(1);
+        let (mut b, clock_b) = seeded(1);
+
+        for _ in 0..2 {
+            assert_eq!(a.feed().to_string(), b.feed().to_string());
+            assert_eq!(a.play().to_string(), b.play().to_string());
+            a.hunger = 80;
+            b.hunger = 80;
+            clock_a.advance(Duration::minutes(30));
+            clock_b.advance(Duration::minutes(30));
+        }
+    }
+
+    #[test]
+    fn different_seeds_pick_different_foods() {
+        let meals: Vec<String> = (0..20)
+            .map(|seed| {
+                let (monster, _clock) = hatch();
+                monster.with_seed(seed).feed().to_string()
+            })
+            .collect();
+
+        assert!(meals.iter().any(|meal| *meal != meals[0]));
+    }
+
+    #[test]
+    fn loading_a_legacy_file_fills_in_sane_defaults() {
+        let path = env::temp_dir().join(format!("mons-box-legacy-{}.json", std::process::id()));
+        fs::write(&path, V0_SAVE).unwrap();
+        // Ages the monster without rolling any random events on the way.
+        let config = Config {
+            max_catch_up_hours: Some(0),
+            ..Config::default()
+        };
+        let loaded = Monster::load_from(&path, &config);
+        fs::remove_file(&path).unwrap();
+
+        let monster = loaded.unwrap().unwrap();
+        assert_eq!(monster.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(monster.name, "Oldie");
+        assert_eq!(monster.skin, Skin::Classic);
+        assert_eq!(
+            (monster.coins, monster.affection, monster.care_streak),
+            (0, 0, 0)
+        );
+        assert!(!monster.sick);
+        assert!(monster.skills.is_empty());
+        assert_eq!(monster.save_format, SaveFormat::Json);
+        assert!(monster.age > 12, "the time since the save wasn't caught up");
     }
 }
@@ -0,0 +1,45 @@
+use rand::{Rng, seq::IndexedRandom};
+
+const ADJECTIVES: &[&str] = &[
+    "Bouncy", "Sleepy", "Fuzzy", "Tiny", "Grumpy", "Sparkly", "Wobbly", "Zippy", "Snuggly",
+    "Cheeky", "Mossy", "Toasty",
+];
+
+const NOUNS: &[&str] = &[
+    "Pudding", "Noodle", "Pebble", "Muffin", "Sprout", "Biscuit", "Button", "Pickle", "Dumpling",
+    "Comet", "Bean", "Waffle",
+];
+
+/// An adjective + noun name such as "Wobbly Noodle", for players who don't pick one.
+pub fn generate(rng: &mut impl Rng) -> String {
+    let adjective = ADJECTIVES.choose(rng).copied().unwrap_or("Little");
+    let noun = NOUNS.choose(rng).copied().unwrap_or("Monster");
+
+    format!("{} {}", adjective, noun)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    #[test]
+    fn the_same_seed_gives_the_same_name() {
+        let name = |seed| generate(&mut StdRng::seed_from_u64(seed));
+
+        assert_eq!(name(7), name(7));
+    }
+
+    #[test]
+    fn names_come_from_the_pool() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let name = generate(&mut rng);
+            let (adjective, noun) = name.split_once(' ').unwrap();
+            assert!(ADJECTIVES.contains(&adjective), "{}", name);
+            assert!(NOUNS.contains(&noun), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/// Something the monster wants the player to do, as returned by `Monster::needs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Need {
+    Feed,
+    Rest,
+    Play,
+    Wake,
+}
+
+impl Need {
+    /// How the monster would ask for it, e.g. "feed me".
+    pub fn phrase(&self) -> &'static str {
+        match self {
+            Need::Feed => "feed me",
+            Need::Rest => "let me sleep",
+            Need::Play => "play with me",
+            Need::Wake => "wake me up",
+        }
+    }
+}
+
+/// Turns a prioritized list into advice such as "Right now: feed me, then let me sleep."
+pub fn advice(needs: &[Need]) -> String {
+    if needs.is_empty() {
+        return "Right now: I'm all good, thanks!".to_string();
+    }
+
+    let steps: Vec<&str> = needs.iter().map(Need::phrase).collect();
+    format!("Right now: {}.", steps.join(", then "))
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// A stat in the range that costs health once the grace period, if any, has run out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Neglect {
+    /// Hunger above 80.
+    Hunger,
+    /// Happiness below 20.
+    Happiness,
+    /// Energy below 10.
+    Energy,
+    /// Cleanliness below `Thresholds::dirty`.
+    Cleanliness,
+}
+
+impl Neglect {
+    pub const ALL: [Neglect; 4] = [
+        Neglect::Hunger,
+        Neglect::Happiness,
+        Neglect::Energy,
+        Neglect::Cleanliness,
+    ];
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+use chrono::Duration;
+
+use crate::app_state::{monster::format_wait, skill::Skill};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    Success {
+        message: String,
+    },
+    /// Nothing changed, unless the refusal itself costs something (a monster pestered to eat
+    /// while full gets grumpy).
+    Refused {
+        name: String,
+        reason: Refusal,
+    },
+    Dead {
+        name: String,
+    },
+}
+
+/// Why an action was turned down, so callers can tell refusals apart without parsing text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Refusal {
+    Sleeping,
+    AlreadyAsleep,
+    AlreadyAwake,
+    TooFull,
+    TooHungryToPlay,
+    TooTiredToPlay,
+    TooTiredToTrain,
+    NotInTheMoodToTrain,
+    /// Medicine given to a healthy monster, which costs it some happiness.
+    NotSick,
+    /// The action is cooling down for this much longer.
+    NotReady(Duration),
+    AlreadyMastered(Skill),
+    NameHasControlCharacters,
+    /// Holds the longest allowed name.
+    NameTooLong(usize),
+    NameBlank,
+}
+
+impl Refusal {
+    /// The message shown to the player about the monster called `name`.
+    pub fn describe(&self, name: &str) -> String {
+        match self {
+            Refusal::Sleeping => format!("😴 {} is sleeping peacefully. Try again later!", name),
+            Refusal::AlreadyAsleep => format!("💤 {} is already asleep.", name),
+            Refusal::AlreadyAwake => format!("👀 {} is already awake.", name),
+            Refusal::TooFull => format!("🤢 {} is too full to eat more!", name),
+            Refusal::TooHungryToPlay => {
+                format!("😵 {} is too hungry to play! Feed them first!", name)
+            }
+            Refusal::TooTiredToPlay => format!("😫 {} is too tired to play right now!", name),
+            Refusal::TooTiredToTrain => format!("😫 {} is too tired to train right now!", name),
+            Refusal::NotInTheMoodToTrain => format!("😒 {} isn't in the mood to train!", name),
+            Refusal::NotSick => format!("😖 {} isn't sick and hated the taste!", name),
+            Refusal::NotReady(remaining) => format!(
+                "⏳ {} isn't ready yet! Try again in {}.",
+                name,
+                format_wait(*remaining)
+            ),
+            Refusal::AlreadyMastered(skill) => {
+                format!("🏆 {} has already mastered {}!", name, skill.name())
+            }
+            Refusal::NameHasControlCharacters => {
+                "✋ Names can't contain control characters.".to_string()
+            }
+            Refusal::NameTooLong(max) => {
+                format!("✋ Names can be at most {} characters long.", max)
+            }
+            Refusal::NameBlank => "✋ A name needs at least one visible character.".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ActionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionOutcome::Success { message } => write!(f, "{}", message),
+            ActionOutcome::Refused { name, reason } => write!(f, "{}", reason.describe(name)),
+            ActionOutcome::Dead { name } => write!(f, "💀 {} has passed away...", name),
+        }
+    }
+}
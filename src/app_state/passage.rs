@@ -0,0 +1,95 @@
+use crate::app_state::life_event::LifeEvent;
+
+/// What one call to `Monster::update_from_time_passage` changed.
+#[derive(Debug, Clone, Default)]
+pub struct TimePassage {
+    /// Whole hours that were applied.
+    pub hours: u32,
+    pub hunger: i16,
+    pub happiness: i16,
+    pub energy: i16,
+    pub health: i16,
+    /// The monster was alive before and isn't anymore.
+    pub died: bool,
+    /// What happened along the way, oldest first.
+    pub events: Vec<LifeEvent>,
+}
+
+impl TimePassage {
+    pub fn is_empty(&self) -> bool {
+        self.hours == 0
+    }
+
+    /// A line such as "While you were away (3h): hunger +6, happiness -3, energy -6", then
+    /// one line per event, e.g. "Fluffy got the zoomies!". Stats that didn't move are left
+    /// out.
+    pub fn summary(&self, name: &str) -> String {
+        let changes: Vec<String> = [
+            ("hunger", self.hunger),
+            ("happiness", self.happiness),
+            ("energy", self.energy),
+            ("health", self.health),
+        ]
+        .iter()
+        .filter(|(_, delta)| *delta != 0)
+        .map(|(stat, delta)| format!("{} {:+}", stat, delta))
+        .collect();
+
+        let mut summary = format!("While you were away ({}h): ", self.hours);
+        if changes.is_empty() {
+            summary.push_str("nothing changed");
+        } else {
+            summary.push_str(&changes.join(", "));
+        }
+        if self.died {
+            summary.push_str(" (and sadly passed away)");
+        }
+        for event in &self.events {
+            summary.push('\n');
+            summary.push_str(&event.kind.describe(name));
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::app_state::life_event::LifeEventKind;
+
+    #[test]
+    fn a_quiet_stretch_says_nothing_changed() {
+        let passage = TimePassage {
+            hours: 2,
+            ..TimePassage::default()
+        };
+
+        assert!(!passage.is_empty());
+        assert_eq!(
+            passage.summary("Testy"),
+            "While you were away (2h): nothing changed"
+        );
+    }
+
+    #[test]
+    fn deaths_and_events_are_listed_after_the_stats() {
+        let passage = TimePassage {
+            hours: 40,
+            health: -100,
+            died: true,
+            events: vec![LifeEvent {
+                kind: LifeEventKind::CaughtCold,
+                at: Utc::now(),
+            }],
+            ..TimePassage::default()
+        };
+
+        assert_eq!(
+            passage.summary("Testy"),
+            "While you were away (40h): health -100 (and sadly passed away)\n🤧 Testy caught a cold!"
+        );
+    }
+}
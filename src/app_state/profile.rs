@@ -0,0 +1,39 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns on timing output for the rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Prints how long it lived to stderr when dropped, if profiling is enabled. When it isn't,
+/// starting one is a single atomic load.
+pub struct Timer {
+    label: &'static str,
+    started_at: Option<Instant>,
+}
+
+impl Timer {
+    pub fn start(label: &'static str) -> Self {
+        Self {
+            label,
+            started_at: ENABLED.load(Ordering::Relaxed).then(Instant::now),
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if let Some(started_at) = self.started_at {
+            eprintln!(
+                "[profile] {}: {:.3}ms",
+                self.label,
+                started_at.elapsed().as_secs_f64() * 1000.0
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/// What the monster might say, keyed by the mood emoji from `Monster::get_mood` like
+/// `sprite::faces`. Every mood has at least one line; the dead say nothing.
+pub fn pool(mood: &str) -> &'static [&'static str] {
+    match mood {
+        "😁" => &[
+            "Best day ever! ⚽",
+            "I could do this forever!",
+            "You're the best! 🌟",
+        ],
+        "😊" => &[
+            "Life is good 🌼",
+            "Got any more of those snacks?",
+            "Let's do something fun!",
+        ],
+        "🙂" => &[
+            "Not bad, not bad.",
+            "Hmm, what's next?",
+            "I'm doing alright.",
+        ],
+        "😐" => &[
+            "I'm a bit bored...",
+            "Could use a snack.",
+            "Is that all there is?",
+        ],
+        "☹️" => &[
+            "Nobody plays with me 😞",
+            "I'm not feeling great.",
+            "Pay attention to me?",
+        ],
+        "😢" => &[
+            "I'm starving 😩",
+            "Please don't forget me...",
+            "Everything hurts.",
+        ],
+        "😵" => &["Help... 🆘", "I can't go on like this..."],
+        "😴" => &[
+            "Zzz... five more minutes...",
+            "Mmm... dreaming of snacks...",
+        ],
+        _ => &[],
+    }
+}
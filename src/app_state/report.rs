@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+use crate::app_state::{monster::Monster, species::Species};
+
+/// The status as printed by `status --format json`. Field names are part of the output
+/// format, so only add to them.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusReport<'a> {
+    pub name: &'a str,
+    pub species: Species,
+    pub hunger: u8,
+    pub happiness: u8,
+    pub energy: u8,
+    pub health: u8,
+    pub cleanliness: u8,
+    pub bond: u8,
+    pub weight: u8,
+    /// In hours.
+    pub age: u32,
+    pub life_stage: &'static str,
+    pub mood: &'a str,
+    pub mood_emoji: &'a str,
+    pub is_sleeping: bool,
+    pub is_alive: bool,
+    pub sick: bool,
+}
+
+impl<'a> From<&'a Monster> for StatusReport<'a> {
+    fn from(monster: &'a Monster) -> Self {
+        let (mood_emoji, mood) = monster.get_mood();
+
+        StatusReport {
+            name: &monster.name,
+            species: monster.species,
+            hunger: monster.hunger,
+            happiness: monster.happiness,
+            energy: monster.energy,
+            health: monster.health,
+            cleanliness: monster.cleanliness,
+            bond: monster.affection,
+            weight: monster.weight,
+            age: monster.age,
+            life_stage: monster.life_stage().name(),
+            mood,
+            mood_emoji,
+            is_sleeping: monster.is_sleeping,
+            is_alive: monster.is_alive,
+            sick: monster.sick,
+        }
+    }
+}
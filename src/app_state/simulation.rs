@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use crate::app_state::{action::Action, clock::MockClock, monster::Monster};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduledAction {
+    /// Offset from the start of the simulation.
+    pub at: Duration,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub elapsed: Duration,
+    pub hunger: u8,
+    pub happiness: u8,
+    pub energy: u8,
+    pub health: u8,
+    pub weight: u8,
+    pub age: u32,
+    pub is_sleeping: bool,
+    pub is_alive: bool,
+    pub sick: bool,
+}
+
+impl Snapshot {
+    fn of(monster: &Monster, elapsed: Duration) -> Self {
+        Self {
+            elapsed,
+            hunger: monster.hunger,
+            happiness: monster.happiness,
+            energy: monster.energy,
+            health: monster.health,
+            weight: monster.weight,
+            age: monster.age,
+            is_sleeping: monster.is_sleeping,
+            is_alive: monster.is_alive,
+            sick: monster.sick,
+        }
+    }
+}
+
+impl Monster {
+    /// Plays a copy of this monster forward on a mock clock, applying each scheduled action
+    /// once its offset is reached, and records a snapshot at the start and after every step.
+    /// The simulation runs until the last scheduled action; schedule `Action::Wait` to run longer.
+    pub fn simulate(&self, schedule: &[ScheduledAction], step: Duration) -> Vec<Snapshot> {
+        assert!(step > Duration::zero(), "simulation step must be positive");
+
+        let start = self.updated_at;
+        let clock = Arc::new(MockClock::new(start));
+        let mut monster = self.clone().with_clock(clock.clone());
+
+        let mut schedule = schedule.to_vec();
+        schedule.sort_by_key(|scheduled| scheduled.at);
+        let end = schedule
+            .last()
+            .map_or(Duration::zero(), |scheduled| scheduled.at);
+
+        let mut pending = schedule.into_iter().peekable();
+        let mut elapsed = Duration::zero();
+        let mut snapshots = vec![Snapshot::of(&monster, elapsed)];
+
+        while elapsed < end {
+            elapsed = (elapsed + step).min(end);
+            clock.set(start + elapsed);
+            monster.update_from_time_passage();
+
+            while let Some(scheduled) = pending.next_if(|scheduled| scheduled.at <= elapsed) {
+                monster.apply(scheduled.action);
+            }
+
+            snapshots.push(Snapshot::of(&monster, elapsed));
+        }
+
+        snapshots
+    }
+}
@@ -0,0 +1,37 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_SKILL_LEVEL: u8 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Skill {
+    /// Slows happiness decay
+    Discipline,
+    /// Slows energy decay
+    Fitness,
+}
+
+impl Skill {
+    pub const ALL: [Skill; 2] = [Skill::Discipline, Skill::Fitness];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Skill::Discipline => "Discipline",
+            Skill::Fitness => "Fitness",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Skill::Discipline => "🎖️",
+            Skill::Fitness => "🏋️",
+        }
+    }
+}
+
+/// Shrinks a decay amount by 10% per skill level.
+pub fn reduce_decay(amount: u8, level: u8) -> u8 {
+    let level = level.min(MAX_SKILL_LEVEL) as u16;
+    (amount as u16 * (10 - level) / 10) as u8
+}
@@ -0,0 +1,199 @@
+use std::{fmt, str::FromStr};
+
+use clap::ValueEnum;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::app_state::food::{Affinity, Food};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Species {
+    /// Balanced in every way
+    #[default]
+    Blob,
+    /// Low-energy but hardy
+    Slime,
+    /// Energetic, and eats a lot
+    Dragon,
+}
+
+/// Stats a freshly hatched monster starts with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Baseline {
+    pub hunger: u8,
+    pub happiness: u8,
+    pub energy: u8,
+    pub health: u8,
+    pub weight: u8,
+}
+
+/// How quickly a species' stats move over time, as a percentage of the usual rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tendencies {
+    pub hunger: u8,
+    pub energy: u8,
+    pub health: u8,
+    /// Share of the configured lifespan the species lives.
+    pub lifespan: u8,
+}
+
+impl Species {
+    pub const ALL: [Species; 3] = [Species::Blob, Species::Slime, Species::Dragon];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Species::Blob => "Blob",
+            Species::Slime => "Slime",
+            Species::Dragon => "Dragon",
+        }
+    }
+
+    pub fn baseline(&self) -> Baseline {
+        let (hunger, happiness, energy, health, weight) = match self {
+            Species::Blob => (50, 70, 80, 100, 50),
+            Species::Slime => (40, 70, 50, 100, 55),
+            Species::Dragon => (60, 60, 100, 100, 60),
+        };
+
+        Baseline {
+            hunger,
+            happiness,
+            energy,
+            health,
+            weight,
+        }
+    }
+
+    pub fn tendencies(&self) -> Tendencies {
+        let (hunger, energy, health, lifespan) = match self {
+            Species::Blob => (100, 100, 100, 100),
+            Species::Slime => (80, 120, 50, 120),
+            Species::Dragon => (150, 80, 100, 150),
+        };
+
+        Tendencies {
+            hunger,
+            energy,
+            health,
+            lifespan,
+        }
+    }
+
+    /// The species' taste in food. Anything not listed is neutral.
+    pub fn affinity(&self, food: Food) -> Affinity {
+        match (self, food) {
+            (Species::Blob, Food::Apple) => Affinity::Liked,
+            (Species::Blob, Food::Fish) => Affinity::Disliked,
+            (Species::Slime, Food::Milk) => Affinity::Liked,
+            (Species::Slime, Food::Meat) => Affinity::Disliked,
+            (Species::Slime, Food::Fish) => Affinity::Forbidden,
+            (Species::Dragon, Food::Meat) => Affinity::Liked,
+            (Species::Dragon, Food::Carrot) => Affinity::Disliked,
+            (Species::Dragon, Food::Milk) => Affinity::Forbidden,
+            _ => Affinity::Neutral,
+        }
+    }
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Which species to hatch: a specific one, or one picked by the monster's RNG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeciesChoice {
+    Random,
+    Exactly(Species),
+}
+
+impl Default for SpeciesChoice {
+    fn default() -> Self {
+        SpeciesChoice::Exactly(Species::default())
+    }
+}
+
+impl SpeciesChoice {
+    pub fn resolve(&self, rng: &mut impl Rng) -> Species {
+        match self {
+            SpeciesChoice::Random => Species::ALL[rng.random_range(0..Species::ALL.len())],
+            SpeciesChoice::Exactly(species) => *species,
+        }
+    }
+}
+
+impl FromStr for SpeciesChoice {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("random") {
+            return Ok(SpeciesChoice::Random);
+        }
+
+        <Species as ValueEnum>::from_str(value, true).map(SpeciesChoice::Exactly)
+    }
+}
+
+/// Scales a per-hour change by a species tendency percentage.
+pub fn scale(amount: u8, percent: u8) -> u8 {
+    (amount as u16 * percent as u16 / 100).min(u8::MAX as u16) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::app_state::monster::{MAX_STAT, Monster};
+
+    #[test]
+    fn every_species_hatches_with_valid_stats() {
+        for species in Species::ALL {
+            let baseline = species.baseline();
+            for stat in [
+                baseline.hunger,
+                baseline.happiness,
+                baseline.energy,
+                baseline.health,
+                baseline.weight,
+            ] {
+                assert!(stat <= MAX_STAT, "{} starts out of range", species);
+            }
+            assert!(baseline.health > 0, "{} hatches dead", species);
+
+            let monster = Monster::new("Testy".to_string(), species);
+            assert_eq!(monster.species, species);
+            assert_eq!(monster.energy, baseline.energy);
+        }
+    }
+
+    #[test]
+    fn the_species_differ() {
+        assert!(Species::Slime.baseline().energy < Species::Blob.baseline().energy);
+        assert!(Species::Slime.tendencies().health < Species::Blob.tendencies().health);
+        assert!(Species::Dragon.tendencies().hunger > Species::Blob.tendencies().hunger);
+    }
+
+    #[test]
+    fn random_picks_the_same_species_for_the_same_seed() {
+        let pick = |seed| SpeciesChoice::Random.resolve(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(pick(3), pick(3));
+
+        let picks: Vec<Species> = (0..50).map(pick).collect();
+        for species in Species::ALL {
+            assert!(picks.contains(&species), "{} never comes up", species);
+        }
+    }
+
+    #[test]
+    fn species_choice_parses_names_and_random() {
+        assert_eq!("Random".parse(), Ok(SpeciesChoice::Random));
+        assert_eq!(
+            "dragon".parse(),
+            Ok(SpeciesChoice::Exactly(Species::Dragon))
+        );
+        assert!("unicorn".parse::<SpeciesChoice>().is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+/// Left eye, mouth and right eye of the awake sprite.
+pub type Face = (&'static str, &'static str, &'static str);
+
+/// Animation frames for the awake sprite, keyed by the mood emoji from `Monster::get_mood`.
+/// The first frame is the resting face; every frame of a mood uses characters of the same
+/// width so the body outline never shifts.
+pub fn faces(mood: &str) -> &'static [Face] {
+    match mood {
+        "😁" => &[("◕", "‿", "◕"), ("-", "‿", "-"), ("◕", "▽", "◕")],
+        "😊" => &[("^", "‿", "^"), ("-", "‿", "-"), ("^", "o", "^")],
+        "🙂" => &[("•", "‿", "•"), ("-", "‿", "-"), ("°", "‿", "°")],
+        "😐" => &[("•", "_", "•"), ("-", "_", "-"), ("•", "-", "•")],
+        "☹️" => &[("•", "︵", "•"), ("-", "︵", "-"), ("ˇ", "︵", "ˇ")],
+        "😢" => &[("╥", "﹏", "╥"), ("-", "﹏", "-"), ("T", "﹏", "T")],
+        "😵" => &[("x", "_", "x"), ("@", "_", "@")],
+        _ => &[("•", "‿", "•")],
+    }
+}
+
+/// Upper bound on the number of frames any animation has.
+pub const MAX_FRAMES: usize = 3;
+
+/// The snore drifting above a sleeping monster, padded to a fixed width.
+pub const SNORES: [&str; 3] = ["zzZ  ", " zzZ ", "  zzZ"];
+
+/// Picks a frame by index, wrapping around so any number is valid.
+pub fn frame<T: Copy>(frames: &[T], index: usize) -> T {
+    frames[index % frames.len()]
+}
+
+/// An egg cracking open, played once when a monster is created. Every frame has the same
+/// number of lines so each can be drawn over the previous one.
+pub const HATCHING: [[&str; 4]; 4] = [
+    ["    ___    ", "   /   \\   ", "  |     |  ", "   \\___/   "],
+    ["    ___    ", "   / , \\   ", "  |  '  |  ", "   \\___/   "],
+    [
+        "    _,_    ",
+        "   / /\\ \\  ",
+        "  | /  \\|  ",
+        "   \\___/   ",
+    ],
+    [
+        "   /\\/\\/\\  ",
+        "  | o o |  ",
+        "  |  ‿  |  ",
+        "   \\___/   ",
+    ],
+];
+
+/// The body outline the monster is drawn with. Purely cosmetic; cycled with `k` in
+/// interactive mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Skin {
+    #[default]
+    Classic,
+    Round,
+    Spiky,
+}
+
+/// Pieces of a skin, each the same width as the classic one so faces stay put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outline {
+    /// Top of the head, 7 columns.
+    pub head: &'static str,
+    pub left: &'static str,
+    pub right: &'static str,
+    /// Bottom of the body, 13 columns.
+    pub base: &'static str,
+}
+
+impl Skin {
+    pub const ALL: [Skin; 3] = [Skin::Classic, Skin::Round, Skin::Spiky];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Skin::Classic => "Classic",
+            Skin::Round => "Round",
+            Skin::Spiky => "Spiky",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Skin::Classic => Skin::Round,
+            Skin::Round => Skin::Spiky,
+            Skin::Spiky => Skin::Classic,
+        }
+    }
+
+    pub fn outline(&self) -> Outline {
+        match self {
+            Skin::Classic => Outline {
+                head: "╭─────╮",
+                left: "╱",
+                right: "╲",
+                base: "╱___________╲",
+            },
+            Skin::Round => Outline {
+                head: ".-----.",
+                left: "(",
+                right: ")",
+                base: "(___________)",
+            },
+            Skin::Spiky => Outline {
+                head: "/\\/\\/\\/",
+                left: "<",
+                right: ">",
+                base: "<___________>",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skins_cycle_through_all_and_back() {
+        let mut skin = Skin::default();
+        for expected in Skin::ALL.iter().cycle().skip(1).take(Skin::ALL.len()) {
+            skin = skin.next();
+            assert_eq!(skin, *expected);
+        }
+        assert_eq!(skin, Skin::default());
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use anyhow::{Context, Result, anyhow};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+pub const STATE_FILE_ENV: &str = "MONS_BOX_STATE";
+const DATA_DIR_NAME: &str = "mons-box";
+const DEFAULT_STATE_FILE: &str = "monster-state.json";
+/// Extra pets are kept as `monster-state-<slot>.json` next to the default save.
+const PET_FILE_PREFIX: &str = "monster-state-";
+/// Where saves used to go before they moved to the data directory.
+const LEGACY_STATE_FILE: &str = ".monster-state.json";
+
+/// Held while the state file is being written. The Ctrl-C handler takes it too, so the
+/// process never exits halfway through a save.
+pub static SAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// How the state file is encoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum SaveFormat {
+    #[default]
+    Json,
+    Toml,
+}
+
+impl SaveFormat {
+    /// Works out how an existing state file is encoded: JSON saves always start with `{`,
+    /// anything else is taken as TOML. An empty file falls back to the extension.
+    pub fn detect(path: &Path, content: &str) -> Self {
+        match content.trim_start().chars().next() {
+            Some('{') => SaveFormat::Json,
+            Some(_) => SaveFormat::Toml,
+            None => Self::from_extension(path),
+        }
+    }
+
+    /// TOML for a `.toml` path, JSON for everything else.
+    pub fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => SaveFormat::Toml,
+            _ => SaveFormat::Json,
+        }
+    }
+
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String> {
+        match self {
+            SaveFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+            SaveFormat::Toml => Ok(toml::to_string_pretty(value)?),
+        }
+    }
+
+    pub fn deserialize<T: DeserializeOwned>(&self, content: &str) -> Result<T> {
+        match self {
+            SaveFormat::Json => serde_json::from_str(content).context("Invalid JSON"),
+            SaveFormat::Toml => toml::from_str(content).context("Invalid TOML"),
+        }
+    }
+}
+
+/// Set from `--state-file` for the rest of the process.
+static STATE_FILE_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Points every later `state_file` call at `path`. Only the first call has any effect.
+pub fn set_state_file(path: PathBuf) {
+    let _ = STATE_FILE_OVERRIDE.set(path);
+}
+
+/// Where the monster is stored. The first of these wins:
+///
+/// 1. the path given to `set_state_file`, i.e. `--state-file`;
+/// 2. `$MONS_BOX_STATE`, if set and not empty;
+/// 3. `mons-box/monster-state.json` in the platform data directory, e.g.
+///    `$XDG_DATA_HOME` or `~/.local/share` on Linux.
+///
+/// Backups, memorials and the undo snapshot all live next to whichever file this is.
+pub fn state_file() -> PathBuf {
+    if let Some(path) = STATE_FILE_OVERRIDE.get() {
+        return path.clone();
+    }
+
+    env::var_os(STATE_FILE_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(default_state_file)
+}
+
+/// The data-directory save, or the legacy file in the working directory on a platform
+/// without one.
+fn default_state_file() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join(DATA_DIR_NAME).join(DEFAULT_STATE_FILE))
+        .unwrap_or_else(|| PathBuf::from(LEGACY_STATE_FILE))
+}
+
+/// Whether `path` is named like the default save rather than a pet or a custom file.
+pub fn is_default_state_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == DEFAULT_STATE_FILE || name == LEGACY_STATE_FILE)
+}
+
+/// The folder the default save and every pet live in.
+fn pets_dir() -> PathBuf {
+    default_state_file()
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+}
+
+/// Turns a pet name into its slot: lowercase, with every run of other characters between
+/// letters and digits replaced by a single `-`. "Mr. Wiggles" becomes `mr-wiggles`.
+pub fn pet_slot(name: &str) -> String {
+    let mut slot = String::new();
+    for c in name.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slot.push(c);
+        } else if !slot.is_empty() && !slot.ends_with('-') {
+            slot.push('-');
+        }
+    }
+
+    slot.trim_end_matches('-').to_string()
+}
+
+/// The state file for the pet called `name`, or `None` if the name has no letters or digits
+/// to make a slot from.
+pub fn pet_state_file(name: &str) -> Option<PathBuf> {
+    let slot = pet_slot(name);
+    (!slot.is_empty()).then(|| pets_dir().join(format!("{}{}.json", PET_FILE_PREFIX, slot)))
+}
+
+/// Every save in the data directory as its slot and path, sorted by slot. The default save
+/// has no slot and comes first.
+pub fn list_pets() -> Result<Vec<(Option<String>, PathBuf)>> {
+    let dir = pets_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut pets = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if file_name == DEFAULT_STATE_FILE {
+            pets.push((None, path));
+        } else if let Some(slot) = file_name
+            .strip_prefix(PET_FILE_PREFIX)
+            .and_then(|rest| rest.strip_suffix(".json"))
+        {
+            pets.push((Some(slot.to_string()), path));
+        }
+    }
+    pets.sort();
+
+    Ok(pets)
+}
+
+/// Moves a `.monster-state.json` left in the working directory by older versions to the
+/// default location, unless something is already saved there. Only the main save moves;
+/// its backups and memorials stay where they were. Returns where the save went, if it moved.
+pub fn migrate_legacy_state_file() -> Result<Option<PathBuf>> {
+    let legacy = PathBuf::from(LEGACY_STATE_FILE);
+    let target = state_file();
+    if target != default_state_file() || target == legacy || !legacy.exists() || target.exists() {
+        return Ok(None);
+    }
+
+    ensure_state_dir(&target)?;
+    // Renaming fails across filesystems, so fall back to a copy.
+    if fs::rename(&legacy, &target).is_err() {
+        fs::copy(&legacy, &target).with_context(|| {
+            format!(
+                "Failed to move {} to {}",
+                legacy.display(),
+                target.display()
+            )
+        })?;
+        fs::remove_file(&legacy)
+            .with_context(|| format!("Failed to remove {}", legacy.display()))?;
+    }
+
+    Ok(Some(target))
+}
+
+/// Where a state file that couldn't be read is set aside: `<state file>.bak`.
+pub fn corrupt_file(state_file: &Path) -> PathBuf {
+    let mut name = state_file
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".bak");
+    state_file.with_file_name(name)
+}
+
+/// Replaces `path` with `content` all at once: the content goes to `<path>.tmp` first and is
+/// then renamed over `path`, so a crash midway leaves the old file intact rather than a
+/// truncated one.
+pub fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let mut name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".tmp");
+    let temp = path.with_file_name(name);
+
+    let mut file =
+        File::create(&temp).with_context(|| format!("Failed to create {}", temp.display()))?;
+    file.write_all(content)
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("Failed to write {}", temp.display()))?;
+    fs::rename(&temp, path).with_context(|| format!("Failed to replace {}", path.display()))
+}
+
+/// Creates the directory that will hold the state file, with an error that says how to
+/// point the game somewhere writable instead.
+pub fn ensure_state_dir(state_file: &Path) -> Result<()> {
+    let Some(dir) = state_file
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+    else {
+        return Ok(());
+    };
+
+    fs::create_dir_all(dir).map_err(|err| {
+        anyhow!(
+            "Couldn't create the data directory {}: {}. Set {} to a writable file path to keep your monster somewhere else.",
+            dir.display(),
+            err,
+            STATE_FILE_ENV
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory of its own for one test.
+    fn scratch_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("mons-box-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn an_uncreatable_data_directory_suggests_the_override() {
+        let dir = scratch_dir("uncreatable");
+        let blocker = dir.join("not-a-directory");
+        fs::write(&blocker, "").unwrap();
+
+        let err = ensure_state_dir(&blocker.join("mons-box").join(DEFAULT_STATE_FILE))
+            .unwrap_err()
+            .to_string();
+
+        assert!(
+            err.starts_with("Couldn't create the data directory"),
+            "{}",
+            err
+        );
+        assert!(err.contains(STATE_FILE_ENV), "{}", err);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn an_existing_data_directory_is_fine() {
+        let dir = scratch_dir("existing");
+
+        ensure_state_dir(&dir.join(DEFAULT_STATE_FILE)).unwrap();
+        ensure_state_dir(Path::new(DEFAULT_STATE_FILE)).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn an_atomic_write_replaces_the_file_and_leaves_no_temp_file() {
+        let dir = scratch_dir("atomic");
+        let path = dir.join(DEFAULT_STATE_FILE);
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, b"new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        let files: Vec<_> = fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// Stat levels at which the monster starts asking for attention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Thresholds {
+    /// Hunger above this is hungry.
+    pub hungry: u8,
+    /// Hunger above this is starving.
+    pub starving: u8,
+    /// Happiness below this is sad.
+    pub sad: u8,
+    /// Energy below this is exhausted.
+    pub exhausted: u8,
+    /// Health below this is unwell.
+    pub unwell: u8,
+    /// Health below this is critical.
+    pub critical_health: u8,
+    /// Cleanliness below this is dirty, which counts as neglect.
+    pub dirty: u8,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            hungry: 70,
+            starving: 90,
+            sad: 30,
+            exhausted: 20,
+            unwell: 50,
+            critical_health: 20,
+            dirty: 25,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+/// The single undo slot, kept next to the state file as `<state file>.undo`.
+pub fn undo_file(state_file: &Path) -> PathBuf {
+    let mut name = state_file
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".undo");
+    state_file.with_file_name(name)
+}
+
+/// Replaces whatever is in the undo slot with `content`.
+pub fn remember(state_file: &Path, content: &str) -> Result<()> {
+    let path = undo_file(state_file);
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write undo snapshot {}", path.display()))
+}
+
+/// Moves the undo snapshot over the state file, emptying the slot. Returns `false` when there
+/// was nothing to undo.
+pub fn restore(state_file: &Path) -> Result<bool> {
+    let path = undo_file(state_file);
+    if !path.exists() {
+        return Ok(false);
+    }
+
+    fs::rename(&path, state_file)
+        .with_context(|| format!("Failed to restore undo snapshot {}", path.display()))?;
+    Ok(true)
+}
+
+pub fn clear(state_file: &Path) -> Result<()> {
+    let path = undo_file(state_file);
+    if path.exists() {
+        fs::remove_file(&path)
+            .with_context(|| format!("Failed to remove undo snapshot {}", path.display()))?;
+    }
+    Ok(())
+}
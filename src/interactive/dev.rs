@@ -0,0 +1,65 @@
+use crate::app_state::monster::{MAX_STAT, Monster};
+
+/// How far one press of `+` or `-` moves a stat.
+pub const STEP: u8 = 5;
+
+/// A stat the debug overlay can edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevStat {
+    Hunger,
+    Happiness,
+    Energy,
+    Health,
+    Cleanliness,
+}
+
+impl DevStat {
+    pub const ALL: [DevStat; 5] = [
+        DevStat::Hunger,
+        DevStat::Happiness,
+        DevStat::Energy,
+        DevStat::Health,
+        DevStat::Cleanliness,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DevStat::Hunger => "Hunger",
+            DevStat::Happiness => "Happiness",
+            DevStat::Energy => "Energy",
+            DevStat::Health => "Health",
+            DevStat::Cleanliness => "Cleanliness",
+        }
+    }
+
+    fn value_mut<'a>(&self, monster: &'a mut Monster) -> &'a mut u8 {
+        match self {
+            DevStat::Hunger => &mut monster.hunger,
+            DevStat::Happiness => &mut monster.happiness,
+            DevStat::Energy => &mut monster.energy,
+            DevStat::Health => &mut monster.health,
+            DevStat::Cleanliness => &mut monster.cleanliness,
+        }
+    }
+
+    pub fn value(&self, monster: &Monster) -> u8 {
+        match self {
+            DevStat::Hunger => monster.hunger,
+            DevStat::Happiness => monster.happiness,
+            DevStat::Energy => monster.energy,
+            DevStat::Health => monster.health,
+            DevStat::Cleanliness => monster.cleanliness,
+        }
+    }
+
+    /// Moves the stat one `STEP` up or down, within `0..=MAX_STAT`. Nothing else about the
+    /// monster changes; the next tick reacts to the new value like any other.
+    pub fn nudge(&self, monster: &mut Monster, up: bool) {
+        let value = self.value_mut(monster);
+        *value = if up {
+            value.saturating_add(STEP).min(MAX_STAT)
+        } else {
+            value.saturating_sub(STEP)
+        };
+    }
+}
@@ -1,27 +1,49 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 use std::{
-    io::{self, StdoutLock, Write},
+    collections::HashMap,
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
     sync::mpsc,
     thread,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 use crossterm::{
     QueueableCommand,
     cursor::MoveTo,
     event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
-    execute,
-    style::{Color, ResetColor, SetForegroundColor},
-    terminal::{
-        Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
-        enable_raw_mode,
+    style::Color,
+    terminal::{self, Clear, ClearType},
+};
+
+use crate::{
+    app_state::{
+        action::Action, alert::Alert, config::Config, food::Food, monster::Monster,
+        species::SpeciesChoice, storage,
     },
+    render::{card, options::DisplayOptions},
+    terminal::{boxes, screen::RawScreen},
 };
 
-use crate::app_state::monster::Monster;
+#[cfg(feature = "dev")]
+use crate::interactive::dev::DevStat;
 
 const TICK_RATE: Duration = Duration::from_millis(60);
 const UI_REFRESH_RATE: Duration = Duration::from_millis(100);
+const DEATH_EXIT_DELAY: Duration = Duration::from_secs(3);
+const QUIT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(3);
+/// Longest time a tick-driven change sits unsaved. Player actions, life events and death are
+/// saved straight away.
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(5);
+const ANIMATION_FRAME: Duration = Duration::from_secs(2);
+/// How long the speech bubble keeps a line, and how long a quiet spell lasts before the
+/// monster pipes up in the message line.
+const QUIP_INTERVAL: Duration = Duration::from_secs(20);
+/// Repeats of the same action closer together than this are one held-down key, not several
+/// presses. Longer than the usual autorepeat delay so the first repeat is caught too.
+const REPEAT_WINDOW: Duration = Duration::from_millis(600);
+/// Half a cycle of the pulse on critical alerts.
+const PULSE_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Debug)]
 pub enum GameEvent {
@@ -33,43 +55,180 @@ pub enum GameEvent {
 pub enum InputEvent {
     Feed,
     Play,
+    Pet,
+    Bath,
+    Medicine,
     Sleep,
+    /// Show or hide the detail panel.
     Status,
     Reset,
+    Help,
+    /// Switch to the next `Layout`.
+    Layout,
+    /// Switch the monster to the next `Skin`.
+    Skin,
+    /// Save now; the only way to persist changes with `no_autosave`.
+    Write,
+    /// A number key, used to pick an entry from a menu.
+    Select(usize),
+    /// Open the debug overlay.
+    #[cfg(feature = "dev")]
+    Dev,
+    /// `+` in the debug overlay.
+    #[cfg(feature = "dev")]
+    Increase,
+    /// `-` in the debug overlay.
+    #[cfg(feature = "dev")]
+    Decrease,
     Quit,
 }
 
+/// What the interactive view is currently showing. Overlays change how keys are read until
+/// they are dismissed; ticks keep running underneath them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Main,
+    FeedMenu,
+    Help,
+    /// Stat editor for testing, only with `dev`.
+    #[cfg(feature = "dev")]
+    Dev,
+}
+
+/// How much of the status the main view shows, cycled with `d`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Sprite, stat bars and info.
+    #[default]
+    Full,
+    /// Sprite plus the one-line summary from `card::draw_compact`.
+    Compact,
+    /// Just the sprite.
+    SpriteOnly,
+}
+
+impl Layout {
+    pub fn next(self) -> Self {
+        match self {
+            Layout::Full => Layout::Compact,
+            Layout::Compact => Layout::SpriteOnly,
+            Layout::SpriteOnly => Layout::Full,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InteractiveOptions {
+    pub exit_on_death: bool,
+    /// Render and tick as usual but ignore every key except quit.
+    pub readonly: bool,
+    /// Keep changes in memory until the player saves with `w` or confirms on quit.
+    pub no_autosave: bool,
+    /// Allow the stat-editing debug overlay, opened with `x`.
+    #[cfg(feature = "dev")]
+    pub dev: bool,
+    /// Species for the monster hatched after a reset.
+    pub species: SpeciesChoice,
+    /// Settings applied to every monster this session loads.
+    pub config: Config,
+    pub display: DisplayOptions,
+}
+
 pub struct InteractiveMode {
     monster: Monster,
+    options: InteractiveOptions,
+    screen: Screen,
+    layout: Layout,
+    /// Show the detail panel under the status, toggled with `i`.
+    detail: bool,
     should_quit: bool,
     message: Option<String>,
     message_timer: Option<Instant>,
+    /// The message is a critical alert and pulses.
+    message_critical: bool,
+    /// When the monster last said something in the message line.
+    last_quip_at: Instant,
+    death_timer: Option<Instant>,
+    quit_armed: Option<Instant>,
+    /// When each action's key was last seen, for collapsing autorepeat bursts.
+    last_action_input: HashMap<Action, Instant>,
+    last_saved_at: Instant,
+    started_at: Instant,
+    /// Modification time of the state file as of our last load or save, used to notice
+    /// another process changing it.
+    last_seen_mtime: Option<SystemTime>,
+    /// Set once the state file was removed by someone else; saving would undo their reset.
+    detached: bool,
+    /// Changes made since the last write, only tracked with `no_autosave`.
+    unsaved: bool,
+    /// A dead monster was replaced in memory with `no_autosave`; the old save is backed up
+    /// and the undo slot cleared by the next write, as `Monster::reset` would have done.
+    reset_pending: bool,
+    /// Which stat the debug overlay is editing.
+    #[cfg(feature = "dev")]
+    dev_stat: DevStat,
 }
 
 impl InteractiveMode {
-    pub fn new(monster: Monster) -> Self {
+    pub fn new(monster: Monster, options: InteractiveOptions) -> Self {
+        // An ephemeral monster has nothing to do with the state file on disk.
+        let detached = monster.ephemeral;
+
         Self {
             monster,
+            options,
+            screen: Screen::Main,
+            layout: Layout::default(),
+            detail: false,
             should_quit: false,
             message: None,
+            message_critical: false,
             message_timer: None,
+            last_quip_at: Instant::now(),
+            death_timer: None,
+            quit_armed: None,
+            last_action_input: HashMap::new(),
+            last_saved_at: Instant::now(),
+            started_at: Instant::now(),
+            last_seen_mtime: state_file_mtime(),
+            detached,
+            unsaved: false,
+            reset_pending: false,
+            #[cfg(feature = "dev")]
+            dev_stat: DevStat::Hunger,
         }
     }
 
     pub fn run(&mut self) -> Result<()> {
-        let mut stdout = io::stdout().lock();
-        execute!(stdout, EnterAlternateScreen)?;
-        enable_raw_mode().context("Failed to enable raw mode")?;
+        // Not locked for the whole session: the Ctrl-C handler writes to stdout too, and would
+        // wait forever while the line loop sits on stdin.
+        let mut stdout = io::stdout();
 
-        let result = self.run_game_loop(&mut stdout);
-
-        disable_raw_mode().context("Failed to disable raw mode")?;
-        execute!(stdout, LeaveAlternateScreen)?;
+        // Only take over the screen once raw mode is known to work; some editors, CI runners
+        // and pipes refuse it, and a plain line-based loop is better than no game at all.
+        // Piped input goes to the line loop too: raw mode would read keys from the terminal
+        // instead and never see it.
+        let screen = if io::stdin().is_terminal() {
+            RawScreen::enter()
+        } else {
+            Err(io::Error::other("input is not a terminal"))
+        };
+        let _screen = match screen {
+            Ok(screen) => screen,
+            Err(err) => {
+                writeln!(
+                    stdout,
+                    "Couldn't switch the terminal to raw mode ({}). Falling back to line mode: type a command and press Enter.",
+                    err
+                )?;
+                return self.run_line_loop(&mut io::stdin().lock(), &mut stdout);
+            }
+        };
 
-        result
+        self.run_game_loop(&mut stdout)
     }
 
-    pub fn run_game_loop(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+    pub fn run_game_loop(&mut self, stdout: &mut impl Write) -> Result<()> {
         stdout.queue(Clear(ClearType::All))?;
         stdout.queue(MoveTo(0, 0))?;
 
@@ -79,16 +238,12 @@ impl InteractiveMode {
 
         thread::spawn(move || {
             loop {
-                if event::poll(UI_REFRESH_RATE).unwrap_or(false) {
-                    if let Ok(event) = event::read() {
-                        if let Event::Key(key_event) = event {
-                            if let Some(input_event) = Self::handle_key_event(key_event) {
-                                if input_sender.send(GameEvent::Input(input_event)).is_err() {
-                                    break;
-                                }
-                            }
-                        }
-                    }
+                if event::poll(UI_REFRESH_RATE).unwrap_or(false)
+                    && let Ok(Event::Key(key_event)) = event::read()
+                    && let Some(input_event) = Self::handle_key_event(key_event)
+                    && input_sender.send(GameEvent::Input(input_event)).is_err()
+                {
+                    break;
                 }
             }
         });
@@ -103,8 +258,8 @@ impl InteractiveMode {
             }
         });
 
-        let mut stdout = io::stdout().lock();
-        self.draw_interface(&mut stdout)?;
+        self.check_alerts();
+        self.draw_interface(stdout)?;
 
         while !self.should_quit {
             if let Ok(event) = receiver.recv_timeout(UI_REFRESH_RATE) {
@@ -117,19 +272,96 @@ impl InteractiveMode {
                     }
                 }
 
-                self.draw_interface(&mut stdout)?;
+                self.draw_interface(stdout)?;
             }
 
-            if let Some(timer) = self.message_timer {
-                if timer.elapsed() > Duration::from_secs(3) {
-                    self.message = None;
-                    self.message_timer = None;
-                    self.draw_interface(&mut stdout)?;
-                }
+            if let Some(timer) = self.message_timer
+                && timer.elapsed() > Duration::from_secs(3)
+            {
+                self.message = None;
+                self.message_timer = None;
+                self.draw_interface(stdout)?;
+            }
+
+            if let Some(timer) = self.quit_armed
+                && timer.elapsed() > QUIT_CONFIRM_TIMEOUT
+            {
+                self.quit_armed = None;
+            }
+
+            if let Some(timer) = self.death_timer
+                && timer.elapsed() > DEATH_EXIT_DELAY
+            {
+                self.save_on_exit()?;
+                self.should_quit = true;
             }
         }
 
-        Ok(())
+        self.save_on_exit()
+    }
+
+    /// The fallback loop for terminals without raw mode: one command per line of `input`,
+    /// with time passage applied before each one.
+    pub fn run_line_loop(
+        &mut self,
+        input: &mut impl BufRead,
+        stdout: &mut impl Write,
+    ) -> Result<()> {
+        let mut line = String::new();
+
+        while !self.should_quit {
+            self.update_monster()?;
+            self.draw_frame(stdout)?;
+            self.message = None;
+            write!(stdout, "> ")?;
+            stdout.flush()?;
+
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            match Self::parse_line(&line) {
+                Some(InputEvent::Quit) if !self.unsaved => self.should_quit = true,
+                Some(input_event) => self.handle_input(input_event)?,
+                None => self.set_message(
+                    "Commands: feed, play, cuddle, sleep, info, reset, help, quit".to_string(),
+                ),
+            }
+        }
+
+        self.save_on_exit()
+    }
+
+    fn parse_line(line: &str) -> Option<InputEvent> {
+        let command = line.trim().to_lowercase();
+
+        match command.as_str() {
+            "f" | "feed" => Some(InputEvent::Feed),
+            "p" | "play" => Some(InputEvent::Play),
+            "c" | "cuddle" | "pet" => Some(InputEvent::Pet),
+            "b" | "bath" => Some(InputEvent::Bath),
+            "m" | "medicine" => Some(InputEvent::Medicine),
+            "s" | "sleep" => Some(InputEvent::Sleep),
+            "i" | "info" | "status" => Some(InputEvent::Status),
+            "r" | "reset" => Some(InputEvent::Reset),
+            "h" | "?" | "help" => Some(InputEvent::Help),
+            "d" | "detail" | "layout" => Some(InputEvent::Layout),
+            "k" | "skin" => Some(InputEvent::Skin),
+            "w" | "write" | "save" => Some(InputEvent::Write),
+            #[cfg(feature = "dev")]
+            "x" | "dev" => Some(InputEvent::Dev),
+            #[cfg(feature = "dev")]
+            "+" => Some(InputEvent::Increase),
+            #[cfg(feature = "dev")]
+            "-" => Some(InputEvent::Decrease),
+            "q" | "quit" | "exit" => Some(InputEvent::Quit),
+            _ => command
+                .parse::<usize>()
+                .ok()
+                .and_then(|number| number.checked_sub(1))
+                .map(InputEvent::Select),
+        }
     }
 
     fn handle_key_event(key_event: KeyEvent) -> Option<InputEvent> {
@@ -159,6 +391,21 @@ impl InteractiveMode {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => Some(InputEvent::Play),
+            KeyEvent {
+                code: KeyCode::Char('c'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Pet),
+            KeyEvent {
+                code: KeyCode::Char('b'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Bath),
+            KeyEvent {
+                code: KeyCode::Char('m'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Medicine),
             KeyEvent {
                 code: KeyCode::Char('s'),
                 modifiers: KeyModifiers::NONE,
@@ -179,82 +426,660 @@ impl InteractiveMode {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => Some(InputEvent::Reset),
+            KeyEvent {
+                code: KeyCode::Char('h' | '?'),
+                ..
+            } => Some(InputEvent::Help),
+            KeyEvent {
+                code: KeyCode::Char('w'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Write),
+            KeyEvent {
+                code: KeyCode::Char('d'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Layout),
+            KeyEvent {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Skin),
+            #[cfg(feature = "dev")]
+            KeyEvent {
+                code: KeyCode::Char('x'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Dev),
+            // `+` needs shift on most layouts, so accept `=` and any modifiers.
+            #[cfg(feature = "dev")]
+            KeyEvent {
+                code: KeyCode::Char('+' | '='),
+                ..
+            } => Some(InputEvent::Increase),
+            #[cfg(feature = "dev")]
+            KeyEvent {
+                code: KeyCode::Char('-'),
+                ..
+            } => Some(InputEvent::Decrease),
+            KeyEvent {
+                code: KeyCode::Char(digit @ '1'..='9'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => Some(InputEvent::Select(digit as usize - '1' as usize)),
             _ => None,
         }
     }
 
     fn update_monster(&mut self) -> Result<()> {
-        self.monster.update_from_time_passage()?;
-        self.monster.save()?;
+        self.check_external_changes()?;
 
-        if !self.monster.is_alive && self.message.is_none() {
-            self.set_message(format!(
-                "💀 {} has died! Press 'r' to start over.",
-                self.monster.name
-            ));
-        } else if self.monster.hunger > 90 && self.message.is_none() {
-            self.set_message(format!(
-                "🚨 {} is starving! Feed them now!",
-                self.monster.name
-            ));
-        } else if self.monster.health < 20 && self.monster.is_alive && self.message.is_none() {
+        let was_alive = self.monster.is_alive;
+        let stage = self.monster.life_stage();
+
+        let last_update = self.monster.updated_at;
+
+        let passage = self.monster.update_from_time_passage();
+
+        let new_event = !passage.events.is_empty();
+        let significant = new_event || self.monster.is_alive != was_alive;
+        let changed = self.monster.updated_at != last_update;
+        if significant || (changed && self.last_saved_at.elapsed() >= AUTOSAVE_INTERVAL) {
+            self.save()?;
+        }
+
+        if let Some(event) = passage.events.last() {
+            self.set_message(event.kind.describe(&self.monster.name));
+        }
+
+        if self.monster.is_alive && self.monster.life_stage() != stage {
             self.set_message(format!(
-                "⚠️ {}'s health is low! Take care of them!",
-                self.monster.name
+                "🎉 {} evolved into a {}!",
+                self.monster.name,
+                self.monster.life_stage().name()
             ));
         }
 
+        if was_alive && !self.monster.is_alive && self.options.exit_on_death {
+            self.death_timer = Some(Instant::now());
+            self.message = None;
+        }
+
+        self.check_alerts();
+        self.maybe_speak();
+
         Ok(())
     }
 
+    /// Lets the monster chime in when nothing else has been said for a while.
+    fn maybe_speak(&mut self) {
+        if self.message.is_some() || self.last_quip_at.elapsed() < QUIP_INTERVAL {
+            return;
+        }
+
+        self.last_quip_at = Instant::now();
+        let quip = self.monster.say();
+        if !quip.is_empty() {
+            self.set_message(format!("{}: \"{}\"", self.monster.name, quip));
+        }
+    }
+
+    fn check_alerts(&mut self) {
+        if self.message.is_some() {
+            return;
+        }
+
+        if let Some(alert) = self.monster.current_alert() {
+            let message = alert.message(&self.monster.name);
+            self.set_message(match alert {
+                Alert::Dead | Alert::PassedAway if self.death_timer.is_some() => {
+                    format!("{} Closing the game...", message)
+                }
+                Alert::Dead | Alert::PassedAway => {
+                    format!("{} Press 'r' to start over.", message)
+                }
+                _ => message,
+            });
+            self.message_critical = alert.is_critical();
+        }
+    }
+
     fn handle_input(&mut self, input_event: InputEvent) -> Result<()> {
+        if !matches!(input_event, InputEvent::Quit) {
+            self.quit_armed = None;
+        }
+
+        // Changing the layout only affects this view, so it works everywhere, even when
+        // observing.
+        if let InputEvent::Layout = input_event {
+            self.layout = self.layout.next();
+            return Ok(());
+        }
+
+        if self.options.readonly && !matches!(input_event, InputEvent::Quit) {
+            return Ok(());
+        }
+
+        match self.screen {
+            Screen::Main => self.handle_main_input(input_event),
+            Screen::FeedMenu => self.handle_feed_menu_input(input_event),
+            Screen::Help => {
+                // Any key closes the help overlay.
+                self.screen = Screen::Main;
+                Ok(())
+            }
+            #[cfg(feature = "dev")]
+            Screen::Dev => self.handle_dev_input(input_event),
+        }
+    }
+
+    #[cfg(feature = "dev")]
+    fn handle_dev_input(&mut self, input_event: InputEvent) -> Result<()> {
+        match input_event {
+            InputEvent::Select(index) => {
+                if let Some(&stat) = DevStat::ALL.get(index) {
+                    self.dev_stat = stat;
+                }
+            }
+            InputEvent::Increase | InputEvent::Decrease => {
+                self.dev_stat.nudge(
+                    &mut self.monster,
+                    matches!(input_event, InputEvent::Increase),
+                );
+                self.save()?;
+            }
+            InputEvent::Quit | InputEvent::Dev => self.screen = Screen::Main,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_feed_menu_input(&mut self, input_event: InputEvent) -> Result<()> {
+        match input_event {
+            InputEvent::Select(index) => {
+                let Some(&food) = Food::ALL.get(index) else {
+                    return Ok(());
+                };
+
+                self.screen = Screen::Main;
+                if self.is_repeat(Action::Feed) {
+                    return Ok(());
+                }
+                let message = self.monster.feed_with(food).to_string();
+                self.set_message(message);
+                self.save()?;
+            }
+            InputEvent::Quit => self.screen = Screen::Main,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_main_input(&mut self, input_event: InputEvent) -> Result<()> {
         let message = match input_event {
-            InputEvent::Feed => self.monster.feed(),
-            InputEvent::Play => self.monster.play(),
-            InputEvent::Sleep => self.monster.toggle_sleep(),
-            InputEvent::Status => "📊 Status updated!".to_string(),
+            InputEvent::Feed => {
+                self.screen = Screen::FeedMenu;
+                return Ok(());
+            }
+            InputEvent::Help => {
+                self.screen = Screen::Help;
+                return Ok(());
+            }
+            InputEvent::Select(_) | InputEvent::Layout => return Ok(()),
+            #[cfg(feature = "dev")]
+            InputEvent::Dev => {
+                if self.options.dev {
+                    self.screen = Screen::Dev;
+                }
+                return Ok(());
+            }
+            #[cfg(feature = "dev")]
+            InputEvent::Increase | InputEvent::Decrease => return Ok(()),
+            InputEvent::Play if self.is_repeat(Action::Play) => return Ok(()),
+            InputEvent::Pet if self.is_repeat(Action::Pet) => return Ok(()),
+            InputEvent::Bath if self.is_repeat(Action::Bath) => return Ok(()),
+            InputEvent::Medicine if self.is_repeat(Action::Medicine) => return Ok(()),
+            InputEvent::Sleep if self.is_repeat(Action::ToggleSleep) => return Ok(()),
+            InputEvent::Play => self.monster.play().to_string(),
+            InputEvent::Pet => self.monster.pet().to_string(),
+            InputEvent::Bath => self.monster.bath().to_string(),
+            InputEvent::Medicine => self.monster.medicine().to_string(),
+            InputEvent::Sleep => self.monster.toggle_sleep().to_string(),
+            InputEvent::Status => {
+                self.detail = !self.detail;
+                return Ok(());
+            }
+            InputEvent::Skin => {
+                self.monster.skin = self.monster.skin.next();
+                format!(
+                    "🎨 {} is wearing the {} skin now.",
+                    self.monster.name,
+                    self.monster.skin.name()
+                )
+            }
             InputEvent::Reset => {
-                if !self.monster.is_alive {
+                if self.monster.ephemeral && !self.monster.is_alive {
+                    self.monster = Monster::from_env_or_default(
+                        None,
+                        self.options.species,
+                        &self.options.config,
+                    )?;
+                    "🔄 Game has been reset! A new monster has been created.".to_string()
+                } else if !self.monster.is_alive && self.options.no_autosave {
+                    // Hatched in memory like an ephemeral monster, but saved as usual once
+                    // the player writes.
+                    let mut monster = Monster::from_env_or_default(
+                        None,
+                        self.options.species,
+                        &self.options.config,
+                    )?;
+                    monster.ephemeral = false;
+                    monster.save_format = self.monster.save_format;
+                    self.monster = monster;
+                    self.reset_pending = true;
+                    "🔄 Game has been reset! A new monster has been created.".to_string()
+                } else if !self.monster.is_alive {
                     Monster::reset()?;
-                    self.monster = Monster::load_or_create()?;
+                    self.monster = Monster::load_or_create(
+                        self.options.species,
+                        &self.options.config,
+                        false,
+                        None,
+                    )?;
                     "🔄 Game has been reset! A new monster has been created.".to_string()
                 } else {
                     "⚠️ Monster is still alive! Reset only works when monster has died.".to_string()
                 }
             }
-            InputEvent::Quit => {
-                self.should_quit = true;
+            InputEvent::Write => {
+                let quitting = self.quit_armed.take().is_some();
+                self.write()?;
+                if quitting {
+                    self.should_quit = true;
+                    return Ok(());
+                }
+
+                self.set_message("💾 Saved.".to_string());
                 return Ok(());
             }
+            InputEvent::Quit => {
+                if self.quit_armed.is_some() {
+                    self.should_quit = true;
+                    return Ok(());
+                }
+
+                self.quit_armed = Some(Instant::now());
+                if self.unsaved {
+                    // Keep the armed state alive across the save below.
+                    self.set_message(
+                        "Unsaved changes — press w to save and quit, or q again to discard them"
+                            .to_string(),
+                    );
+                    return Ok(());
+                }
+                if self.options.no_autosave {
+                    self.set_message("Nothing to save — press q again to quit".to_string());
+                    return Ok(());
+                }
+                "Saved ✓ — press q again to quit".to_string()
+            }
         };
 
         self.set_message(message);
+        self.save()
+    }
+
+    /// Records an input for `action` and tells whether it continues a burst that already
+    /// applied it. Every repeat pushes the window forward, so holding a key applies it once.
+    fn is_repeat(&mut self, action: Action) -> bool {
+        let now = Instant::now();
+        self.last_action_input
+            .insert(action, now)
+            .is_some_and(|last| now.duration_since(last) < REPEAT_WINDOW)
+    }
+
+    /// The monster as the session left it.
+    pub fn monster(&self) -> &Monster {
+        &self.monster
+    }
+
+    /// Whether quitting now would lose anything; only possible with `no_autosave`.
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.unsaved
+    }
+
+    /// Autosave point after a change: writes the monster, or with `no_autosave` just notes
+    /// that there are changes to write.
+    fn save(&mut self) -> Result<()> {
+        if self.options.no_autosave {
+            self.unsaved = true;
+            return Ok(());
+        }
+
+        self.write()
+    }
+
+    /// Writes the monster on the way out. With `no_autosave` that is left to the player, and
+    /// nothing new is marked unsaved.
+    fn save_on_exit(&mut self) -> Result<()> {
+        if self.options.no_autosave {
+            return Ok(());
+        }
+
+        self.write()
+    }
+
+    fn write(&mut self) -> Result<()> {
+        if self.detached {
+            return Ok(());
+        }
+
+        if self.reset_pending {
+            Monster::reset()?;
+            self.reset_pending = false;
+        }
+
         self.monster.save()?;
+        self.last_saved_at = Instant::now();
+        self.last_seen_mtime = state_file_mtime();
+        self.unsaved = false;
+        Ok(())
+    }
+
+    /// Picks up changes another process made to the state file, such as `reset` run from a
+    /// second terminal, instead of overwriting them with this session's copy.
+    fn check_external_changes(&mut self) -> Result<()> {
+        if self.detached {
+            return Ok(());
+        }
+
+        let mtime = state_file_mtime();
+        if mtime == self.last_seen_mtime {
+            return Ok(());
+        }
+
+        // Read only: recovering, let alone asking about it, is no job for a running session.
+        let reloaded = match Monster::load_from(&storage::state_file(), &self.options.config) {
+            Ok(reloaded) => reloaded,
+            Err(_) => {
+                self.last_seen_mtime = mtime;
+                self.set_message(
+                    "⚠️ The save file changed elsewhere but couldn't be read; keeping this session's monster."
+                        .to_string(),
+                );
+                return Ok(());
+            }
+        };
+        match reloaded {
+            Some(monster) => {
+                self.monster = monster;
+                self.last_seen_mtime = mtime;
+                self.set_message(format!(
+                    "🔁 Reloaded {} because the save file changed elsewhere.",
+                    self.monster.name
+                ));
+            }
+            None => {
+                self.detached = true;
+                self.set_message(
+                    "🔄 The monster was reset from another terminal. This session won't save anymore; restart to continue."
+                        .to_string(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_interface(&self, stdout: &mut impl Write) -> Result<()> {
+        // Redraw from the top and wipe whatever the previous frame left below, so closing an
+        // overlay, shortening a message or switching to a smaller layout leaves nothing behind.
+        stdout.queue(MoveTo(0, 0))?;
+        stdout.queue(Clear(ClearType::FromCursorDown))?;
+
+        let mut body = Vec::new();
+        self.draw_body(&mut body)?;
+        let mut panel = Vec::new();
+        self.draw_panel(&mut panel)?;
+        stdout.write_all(&body)?;
+
+        // Pin the panel to the bottom row so it doesn't move as warnings come and go. A
+        // terminal too short for both just gets the panel straight after the status.
+        let body_height = line_count(&body);
+        let panel_height = line_count(&panel);
+        if let Ok((_, rows)) = terminal::size()
+            && rows >= body_height + panel_height
+        {
+            // A newline after the bottom row would scroll the whole screen up by one.
+            stdout.queue(MoveTo(0, rows - panel_height))?;
+            stdout.write_all(panel.strip_suffix(b"\r\n").unwrap_or(&panel))?;
+        } else {
+            stdout.write_all(&panel)?;
+        }
+        stdout.flush()?;
 
         Ok(())
     }
 
-    fn draw_interface(&self, stdout: &mut StdoutLock) -> Result<()> {
-        self.monster.display(stdout)?;
+    /// Draws everything in one flow, for the line-based loop.
+    fn draw_frame(&self, stdout: &mut impl Write) -> Result<()> {
+        self.draw_body(stdout)?;
+        self.draw_panel(stdout)?;
+        stdout.flush()?;
+
+        Ok(())
+    }
+
+    /// The monster and the latest message.
+    fn draw_body(&self, stdout: &mut impl Write) -> Result<()> {
+        let caps = &self.options.display.capabilities;
+
+        let display = DisplayOptions {
+            frame: (self.started_at.elapsed().as_millis() / ANIMATION_FRAME.as_millis()) as usize,
+            quip: (self.started_at.elapsed().as_millis() / QUIP_INTERVAL.as_millis()) as usize,
+            ..self.options.display
+        };
+        match self.layout {
+            Layout::Full => card::draw_status(stdout, &self.monster, &display)?,
+            Layout::Compact => {
+                card::draw_sprite(stdout, &self.monster, &display)?;
+                writeln!(stdout)?;
+
+                // The compact renderer writes plain newlines, which raw mode won't return
+                // to the first column.
+                let mut buffer = Vec::new();
+                card::draw_compact(&mut buffer, &self.monster, &display)?;
+                for line in String::from_utf8_lossy(&buffer).lines() {
+                    write!(stdout, "{}\r\n", line)?;
+                }
+            }
+            Layout::SpriteOnly => card::draw_sprite(stdout, &self.monster, &display)?,
+        }
+
+        if self.detail {
+            write!(stdout, "\r\n{}\r\n", caps.text("🔎 Details:"))?;
+            for line in self.monster.details() {
+                write!(stdout, "   {}\r\n", line)?;
+            }
+        }
 
         // Draw message if any
         if let Some(ref message) = self.message {
-            stdout.queue(SetForegroundColor(Color::Cyan))?;
+            let pulse_on = (self.started_at.elapsed().as_millis() / PULSE_INTERVAL.as_millis())
+                .is_multiple_of(2);
+            let (color, marker) = match (self.message_critical, pulse_on) {
+                (false, _) => (Color::Cyan, ""),
+                (true, true) => (Color::Red, "!!! "),
+                (true, false) => (Color::DarkRed, "    "),
+            };
+            let marker = if self.message_critical && !caps.color {
+                marker
+            } else {
+                ""
+            };
+
+            caps.set_color(stdout, color)?;
             writeln!(stdout)?;
-            write!(stdout, "💬 {}\r\n", message)?;
-            stdout.queue(ResetColor)?;
+            write!(
+                stdout,
+                "{}{}{}\r\n",
+                caps.glyph("💬 ", "> "),
+                marker,
+                caps.text(message)
+            )?;
+            caps.reset_color(stdout)?;
+        }
+
+        Ok(())
+    }
+
+    /// The controls box, or whichever overlay replaces it.
+    fn draw_panel(&self, stdout: &mut impl Write) -> Result<()> {
+        match self.screen {
+            Screen::Main => self.draw_controls(stdout),
+            Screen::FeedMenu => self.draw_feed_menu(stdout),
+            Screen::Help => self.draw_help(stdout),
+            #[cfg(feature = "dev")]
+            Screen::Dev => self.draw_dev(stdout),
+        }
+    }
+
+    fn draw_controls(&self, stdout: &mut impl Write) -> Result<()> {
+        let width = self.options.display.box_width();
+
+        if self.options.readonly {
+            let caps = &self.options.display.capabilities;
+            write!(stdout, "{}\r\n", boxes::top(width))?;
+            write!(
+                stdout,
+                "{}\r\n",
+                boxes::centered(&format!("{}OBSERVE MODE", caps.glyph("👀 ", "   ")), width)
+            )?;
+            write!(stdout, "{}\r\n", boxes::divider(width))?;
+            write!(
+                stdout,
+                "{}\r\n",
+                boxes::row(" Inputs are ignored.  [Q]uit", width)
+            )?;
+            write!(stdout, "{}\r\n", boxes::bottom(width))?;
+            return Ok(());
         }
 
-        // Draw controls at bottom
         // writeln!(stdout)?;
-        write!(stdout, "╭─────────────────────────────────╮\r\n")?;
-        write!(stdout, "│            CONTROLS             │\r\n")?;
-        write!(stdout, "├─────────────────────────────────┤\r\n")?;
-        write!(stdout, "│ [F]eed  [P]lay  [S]leep  [I]nfo │\r\n")?;
-        write!(stdout, "│ [R]eset  [Q]uit                │\r\n")?;
-        write!(stdout, "╰─────────────────────────────────╯\r\n")?;
+        let title = if self.unsaved {
+            "CONTROLS  (unsaved)"
+        } else {
+            "CONTROLS"
+        };
+        write!(stdout, "{}\r\n", boxes::top(width))?;
+        write!(stdout, "{}\r\n", boxes::centered(title, width))?;
+        write!(stdout, "{}\r\n", boxes::divider(width))?;
+        write!(
+            stdout,
+            "{}\r\n",
+            boxes::row(" [F]eed  [P]lay  [B]ath  [S]leep", width)
+        )?;
+        write!(
+            stdout,
+            "{}\r\n",
+            boxes::row(" [C]uddle [M]edicine [I]nfo", width)
+        )?;
+        write!(
+            stdout,
+            "{}\r\n",
+            boxes::row(" [R]eset [H]elp [Q]uit", width)
+        )?;
+        write!(stdout, "{}\r\n", boxes::bottom(width))?;
 
-        stdout.flush()?;
+        Ok(())
+    }
+
+    fn draw_feed_menu(&self, stdout: &mut impl Write) -> Result<()> {
+        let caps = &self.options.display.capabilities;
+        let width = self.options.display.box_width();
+
+        write!(stdout, "{}\r\n", boxes::top(width))?;
+        write!(stdout, "{}\r\n", boxes::centered("FEED MENU", width))?;
+        write!(stdout, "{}\r\n", boxes::divider(width))?;
+        for (index, food) in Food::ALL.iter().enumerate() {
+            let line = format!(
+                "  [{}] {} {}",
+                index + 1,
+                caps.glyph(food.emoji(), "  "),
+                food.name()
+            );
+            write!(stdout, "{}\r\n", boxes::row(&line, width))?;
+        }
+        write!(stdout, "{}\r\n", boxes::row("  [Esc] Cancel", width))?;
+        write!(stdout, "{}\r\n", boxes::bottom(width))?;
+
+        Ok(())
+    }
+
+    fn draw_help(&self, stdout: &mut impl Write) -> Result<()> {
+        const LINES: [&str; 12] = [
+            " F  choose a food to feed",
+            " P  play to raise happiness",
+            " C  cuddle for a small top-up",
+            " B  bath to get clean",
+            " M  medicine for a cold",
+            " S  put to sleep / wake up",
+            " I  show or hide details",
+            " R  start over once it has died",
+            " D  show more or less detail",
+            " K  change skin",
+            " W  save (with --no-autosave)",
+            " Q  quit (press twice)",
+        ];
+        let width = self.options.display.box_width();
+
+        write!(stdout, "{}\r\n", boxes::top(width))?;
+        write!(stdout, "{}\r\n", boxes::centered("HELP", width))?;
+        write!(stdout, "{}\r\n", boxes::divider(width))?;
+        for line in LINES {
+            write!(stdout, "{}\r\n", boxes::row(line, width))?;
+        }
+        write!(stdout, "{}\r\n", boxes::divider(width))?;
+        write!(
+            stdout,
+            "{}\r\n",
+            boxes::centered("Press any key to close", width)
+        )?;
+        write!(stdout, "{}\r\n", boxes::bottom(width))?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "dev")]
+    fn draw_dev(&self, stdout: &mut impl Write) -> Result<()> {
+        let width = self.options.display.box_width();
+
+        write!(stdout, "{}\r\n", boxes::top(width))?;
+        write!(
+            stdout,
+            "{}\r\n",
+            boxes::centered("DEBUG: EDIT STATS", width)
+        )?;
+        write!(stdout, "{}\r\n", boxes::divider(width))?;
+        for (index, stat) in DevStat::ALL.iter().enumerate() {
+            let line = format!(
+                " {} [{}] {:<12} {:>3}",
+                if *stat == self.dev_stat { ">" } else { " " },
+                index + 1,
+                stat.name(),
+                stat.value(&self.monster)
+            );
+            write!(stdout, "{}\r\n", boxes::row(&line, width))?;
+        }
+        write!(stdout, "{}\r\n", boxes::divider(width))?;
+        write!(
+            stdout,
+            "{}\r\n",
+            boxes::row(" 1-5 pick  +/- change  Esc close", width)
+        )?;
+        write!(stdout, "{}\r\n", boxes::bottom(width))?;
 
         Ok(())
     }
@@ -262,5 +1087,144 @@ impl InteractiveMode {
     fn set_message(&mut self, message: String) {
         self.message = Some(message);
         self.message_timer = Some(Instant::now());
+        self.message_critical = false;
+    }
+}
+
+fn line_count(rendered: &[u8]) -> u16 {
+    let lines = rendered.iter().filter(|&&byte| byte == b'\n').count();
+    u16::try_from(lines).unwrap_or(u16::MAX)
+}
+
+fn state_file_mtime() -> Option<SystemTime> {
+    fs::metadata(storage::state_file())
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::app_state::{species::Species, sprite::Skin};
+
+    /// A session around a monster that never touches the state file.
+    fn session() -> InteractiveMode {
+        let mut monster = Monster::new("Testy".to_string(), Species::Blob);
+        monster.ephemeral = true;
+        InteractiveMode::new(monster, InteractiveOptions::default())
+    }
+
+    #[test]
+    fn line_mode_runs_one_command_per_line() {
+        let mut session = session();
+        let hunger = session.monster().hunger;
+        let mut out = Vec::new();
+
+        session
+            .run_line_loop(&mut Cursor::new("feed\n1\nquit\n"), &mut out)
+            .unwrap();
+
+        assert!(session.monster().hunger < hunger);
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("> ").count(), 3);
+        assert!(out.contains("Testy"));
+    }
+
+    #[test]
+    fn line_mode_stops_at_the_end_of_input() {
+        let mut session = session();
+        let mut out = Vec::new();
+
+        session
+            .run_line_loop(&mut Cursor::new("nonsense\n"), &mut out)
+            .unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Commands: feed, play"));
+    }
+
+    #[test]
+    fn a_held_sleep_key_toggles_once() {
+        let mut session = session();
+
+        for _ in 0..4 {
+            session.handle_input(InputEvent::Sleep).unwrap();
+        }
+        assert!(session.monster().is_sleeping);
+
+        thread::sleep(REPEAT_WINDOW);
+        session.handle_input(InputEvent::Sleep).unwrap();
+        assert!(!session.monster().is_sleeping);
+    }
+
+    #[test]
+    fn a_burst_of_feeds_applies_one_meal() {
+        let mut session = session();
+        session.monster.hunger = 90;
+
+        session.handle_input(InputEvent::Feed).unwrap();
+        session.handle_input(InputEvent::Select(1)).unwrap();
+        let fed = session.monster().hunger;
+        let message = session.message.clone();
+        assert!(fed < 90);
+
+        for _ in 0..5 {
+            session.handle_input(InputEvent::Feed).unwrap();
+            session.handle_input(InputEvent::Select(1)).unwrap();
+        }
+        assert_eq!(session.monster().hunger, fed);
+        assert_eq!(session.message, message);
+        assert_eq!(session.screen, Screen::Main);
+    }
+
+    #[test]
+    fn status_toggles_the_detail_panel() {
+        let mut session = session();
+        let frame = |session: &InteractiveMode| {
+            let mut out = Vec::new();
+            session.draw_frame(&mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert!(!session.detail);
+        assert!(!frame(&session).contains("Details:"));
+
+        session.handle_input(InputEvent::Status).unwrap();
+        assert!(session.detail);
+        assert!(frame(&session).contains("Details:"));
+
+        session.handle_input(InputEvent::Status).unwrap();
+        assert!(!session.detail);
+        assert!(!frame(&session).contains("Details:"));
+    }
+
+    #[test]
+    fn the_full_layout_draws_the_same_card_as_status() {
+        let session = session();
+        let mut card = Vec::new();
+        card::draw_status(&mut card, session.monster(), &session.options.display).unwrap();
+
+        let mut frame = Vec::new();
+        session.draw_body(&mut frame).unwrap();
+        assert!(frame.starts_with(&card));
+    }
+
+    #[test]
+    fn the_skin_key_changes_the_skin_and_marks_it_for_saving() {
+        let mut monster = Monster::new("Testy".to_string(), Species::Blob);
+        monster.ephemeral = true;
+        let options = InteractiveOptions {
+            no_autosave: true,
+            ..InteractiveOptions::default()
+        };
+        let mut session = InteractiveMode::new(monster, options);
+
+        session.handle_input(InputEvent::Skin).unwrap();
+        assert_eq!(session.monster().skin, Skin::Round);
+        assert!(
+            session.has_unsaved_changes(),
+            "the new skin wasn't marked for saving"
+        );
     }
 }
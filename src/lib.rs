@@ -1,2 +1,5 @@
 pub mod app_state;
 pub mod interactive;
+pub mod remind;
+pub mod render;
+pub mod terminal;
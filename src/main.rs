@@ -1,70 +1,625 @@
 use std::{
+    env, fs,
     io::{self, IsTerminal, Write},
-    process::ExitCode,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
 };
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use chrono::Local;
+use clap::{Parser, Subcommand, ValueEnum};
+use crossterm::{
+    execute,
+    terminal::{LeaveAlternateScreen, disable_raw_mode},
+};
+use rand::Rng;
 
-use mons_box_cli::{app_state::monster::Monster, interactive::event::InteractiveMode};
+use mons_box_cli::{
+    app_state::{
+        alert::Alert,
+        comparison::{self, Side},
+        config::{self, Config},
+        food::Food,
+        hall_of_fame,
+        monster::{self, Monster},
+        need, profile,
+        report::StatusReport,
+        skill::Skill,
+        species::{Species, SpeciesChoice},
+        sprite,
+        storage::{self, SaveFormat},
+    },
+    interactive::event::{InteractiveMode, InteractiveOptions},
+    remind::scheduler::Scheduler,
+    render::{
+        card,
+        options::{DisplayOptions, WarningLevel},
+    },
+    terminal::{capabilities::Capabilities, prompt},
+};
 
+/// Take care of a virtual monster from your terminal.
+///
+/// Running without a command shows the monster's status.
+///
+/// Ctrl-C is always safe: a save that has started is finished first, and otherwise nothing
+/// is written, so the save file is never left half-written.
+///
+/// Exit codes: 0 success; 1 error; 3 health below `--min-health-exit`. `status` instead
+/// exits with 0 when the monster is fine; 1 when it is dead; 2 when it is critical (hunger
+/// above 80, energy below 10 or health below 20). `status --stats-only` exits with the
+/// health band. Low health still takes precedence with 3.
 #[derive(Parser)]
 struct Args {
     #[command(subcommand)]
     command: Option<SubCommands>,
+    /// Replace emoji with plain text
+    #[arg(long, global = true)]
+    ascii: bool,
+    /// Disable colored output
+    #[arg(long, global = true)]
+    no_color: bool,
+    /// Color stat bars by a single threshold instead of a gradient
+    #[arg(long, global = true)]
+    simple_bars: bool,
+    /// Draw boxes and bars this many columns wide (at least 35)
+    #[arg(long, global = true, value_name = "COLUMNS")]
+    output_width: Option<usize>,
+    /// Exit with code 3 if health ends up below N, after the command and any decay
+    #[arg(long, global = true, value_name = "N")]
+    min_health_exit: Option<u8>,
+    /// Which warnings to print under the stats
+    #[arg(long, global = true, value_name = "LEVEL", default_value = "all")]
+    warnings: WarningLevel,
+    /// Don't report what changed while you were away
+    #[arg(long, short, global = true)]
+    quiet: bool,
+    /// Forgive decay beyond this many hours away (overrides the config file)
+    #[arg(long, global = true, value_name = "HOURS")]
+    max_catch_up: Option<u32>,
+    /// Read settings from this file instead of the default config file
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<PathBuf>,
+    /// Species to hatch when a new monster is created: blob, slime, dragon or random
+    #[arg(long, global = true, value_name = "SPECIES", default_value = "blob")]
+    species: SpeciesChoice,
+    /// Name a newly hatched monster this instead of asking; ignored once one exists
+    #[arg(long, global = true, value_name = "NAME")]
+    name: Option<String>,
+    /// Fail instead of starting over when the save is damaged and no backup can be read
+    #[arg(long, global = true)]
+    strict: bool,
+    /// Seed every random choice (names, foods, activities, events) to replay a run exactly
+    #[arg(long, global = true, value_name = "SEED")]
+    seed: Option<u64>,
+    /// Write the state file as json or toml (overrides the config file)
+    #[arg(long, global = true, value_name = "FORMAT")]
+    save_format: Option<SaveFormat>,
+    /// Print how long loading, saving and catching up take, to stderr
+    #[arg(long, global = true)]
+    profile: bool,
+    /// Skip the hatching animation and the welcome banner
+    #[arg(long, global = true)]
+    no_intro: bool,
+    /// Use a throwaway monster kept in memory; the state file is never read or written
+    #[arg(long, global = true)]
+    ephemeral: bool,
+    /// Keep the monster in this file, ahead of $MONS_BOX_STATE and the default
+    #[arg(long, global = true, value_name = "PATH", conflicts_with = "ephemeral")]
+    state_file: Option<PathBuf>,
+    /// Look after the pet with this name instead of the main one; see `list`
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        conflicts_with_all = ["ephemeral", "state_file"]
+    )]
+    pet: Option<String>,
+    /// JSON fields for the --ephemeral monster, e.g. '{"hunger": 90}' (default: $MONS_BOX_STATS)
+    #[arg(long, global = true, value_name = "JSON", requires = "ephemeral")]
+    stats: Option<String>,
 }
 
 #[derive(Subcommand)]
 enum SubCommands {
     /// Feed your monster to reduce hunger
-    Feed,
+    #[command(visible_alias = "f")]
+    Feed {
+        /// What to feed; picks something at random when omitted
+        #[arg(value_enum)]
+        food: Option<Food>,
+    },
     /// Play with your monster to increase happiness
+    #[command(visible_alias = "p")]
     Play,
-    /// Clean your monster to increase cleanliness
+    /// Give your monster a cuddle for a small happiness boost
+    Pet,
+    /// Give your monster a bath to increase cleanliness
+    #[command(visible_alias = "b")]
+    Bath,
+    /// Give your monster medicine to cure a cold and restore some health
+    Medicine,
+    /// Put your monster to sleep (does nothing if it is already asleep)
+    #[command(visible_alias = "s")]
     Sleep,
+    /// Wake your monster up (does nothing if it is already awake)
+    Wake,
+    /// Train a skill to permanently improve your monster
+    Train {
+        #[arg(value_enum)]
+        skill: Skill,
+    },
+    /// Give your monster a new name
+    Rename {
+        /// Up to 24 characters
+        new_name: String,
+    },
     /// Show details about your monster
-    Status,
+    ///
+    /// Exit codes, the same with or without `--stats-only`:
+    ///
+    /// - 0: your monster is fine
+    /// - 1: it is dead
+    /// - 2: it is critical (hunger above 80, energy below 10 or health below the critical
+    ///   threshold)
+    /// - 3: its health is below `--min-health-exit`
+    #[command(visible_alias = "st")]
+    Status {
+        /// Print a one-line summary instead of the full status card
+        #[arg(long)]
+        compact: bool,
+        /// Print one machine-readable line instead of the status card
+        ///
+        /// The line holds, in order: name, health, hunger, happiness, energy, weight, age,
+        /// alive, sleeping, sick and band, as `key=value` pairs. Booleans are 0 or 1 and a
+        /// name with spaces is double-quoted.
+        ///
+        /// The exit code is the same as plain `status`; a warning (hungry, sad, exhausted,
+        /// unwell or sick) only shows up as `band=warning`.
+        #[arg(long, conflicts_with_all = ["compact", "output"])]
+        stats_only: bool,
+        /// Write the status card as plain text to a file instead of the terminal
+        #[arg(long, value_name = "PATH")]
+        output: Option<PathBuf>,
+        /// Print the status in another format instead of the status card
+        #[arg(long, value_enum, conflicts_with_all = ["compact", "stats_only", "output"])]
+        format: Option<StatusFormat>,
+    },
+    /// List recent things that happened to your monster
+    Events,
+    /// Ask your monster what it needs right now
+    Advice,
+    /// Show which foods your monster's species likes, dislikes or must not eat
+    Diet,
+    /// Print a one-line summary for embedding in a shell prompt
+    ///
+    /// Prints nothing if no monster exists yet and never writes the save file, so it is cheap
+    /// enough to run on every prompt:
+    ///
+    ///   bash:  PS1='$(mons-box-cli prompt) \$ '
+    ///   zsh:   setopt PROMPT_SUBST; PROMPT='$(mons-box-cli prompt) %# '
+    ///   fish:  function fish_right_prompt; mons-box-cli prompt; end
+    #[command(verbatim_doc_comment)]
+    Prompt,
+    /// Print any alert that needs attention; meant to be run by a scheduler
+    Notify,
+    /// Print a scheduler entry that runs `notify` periodically
+    Remind {
+        /// Minutes between checks
+        #[arg(long, default_value_t = 30)]
+        every: u32,
+        /// Register the entry with the system scheduler
+        #[arg(long)]
+        install: bool,
+    },
     /// Start interactive real-time mode
-    Interactive,
+    Interactive {
+        /// Leave interactive mode shortly after the monster dies
+        #[arg(long)]
+        exit_on_death: bool,
+        /// Only watch: every key except quit is ignored
+        #[arg(long)]
+        readonly: bool,
+        /// Only save when you press w, or confirm when quitting
+        #[arg(long, conflicts_with = "readonly")]
+        no_autosave: bool,
+        /// Enable the debug overlay (x) for editing stats
+        #[cfg(feature = "dev")]
+        #[arg(long, conflicts_with = "readonly")]
+        dev: bool,
+    },
+    /// Show every species in every mood, and the stat bars in each style
+    ThemePreview,
+    /// Compare two saved monsters side by side
+    Compare {
+        /// First state file
+        a: PathBuf,
+        /// Second state file
+        b: PathBuf,
+    },
+    /// Take back the last feed, play, pet, bath, medicine, sleep or train
+    Undo,
+    /// Inspect the settings in use
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Lay a dead monster to rest: show its memorial and archive it
+    Memorial,
+    /// Rank every monster you've raised, departed and active, by lifespan and care
+    HallOfFame,
+    /// List every pet kept with --pet, and the main one, with their mood
+    List,
+    /// Check that a pet exists before looking after it with --pet
+    Switch {
+        /// Name the pet was created with
+        name: String,
+    },
     /// Reset the game (create a new monster)
     Reset,
+    /// List backups made by reset, or restore one of them
+    Restore {
+        /// Number or file name of the backup to restore
+        backup: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print the effective settings, with defaults filled in, as JSON
+    Print,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum StatusFormat {
+    /// A heading and a stats table, for pasting into chats and issues
+    Markdown,
+    /// One JSON object with the stats, age, mood and whether it is asleep or alive, for
+    /// scripts and widgets
+    Json,
+}
+
+impl SubCommands {
+    /// Commands that only look at the monster and must not create one.
+    fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            SubCommands::Status { .. }
+                | SubCommands::Events
+                | SubCommands::Advice
+                | SubCommands::Diet
+                | SubCommands::Notify
+                | SubCommands::Memorial
+        )
+    }
+
+    /// Commands that only make sense against the state file, so not with `--ephemeral`.
+    fn needs_state_file(&self) -> bool {
+        matches!(
+            self,
+            SubCommands::Restore { .. }
+                | SubCommands::Undo
+                | SubCommands::Reset
+                | SubCommands::Memorial
+                | SubCommands::HallOfFame
+                | SubCommands::List
+                | SubCommands::Switch { .. }
+                | SubCommands::Prompt
+        )
+    }
 }
 
 fn main() -> Result<ExitCode> {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    if args.profile {
+        profile::enable();
+    }
+    if let Some(path) = args.state_file.take() {
+        storage::set_state_file(path);
+    }
+    if let Some(seed) = args.seed {
+        monster::set_seed(seed);
+    }
+    if let Some(name) = &args.pet {
+        let Some(path) = storage::pet_state_file(name) else {
+            eprintln!("A pet name needs at least one letter or digit.");
+            return Ok(ExitCode::FAILURE);
+        };
+        storage::set_state_file(path);
+    }
+
+    ctrlc::set_handler(|| {
+        let _guard = storage::SAVE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Interactive mode may have the terminal in raw mode.
+        if io::stdout().is_terminal() {
+            let _ = disable_raw_mode();
+            let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        }
+        println!();
+        process::exit(130);
+    })
+    .context("Failed to install the Ctrl-C handler")?;
+
+    if args.ephemeral
+        && args
+            .command
+            .as_ref()
+            .is_some_and(SubCommands::needs_state_file)
+    {
+        eprintln!("That command works on the state file, so it can't be used with --ephemeral.");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    if !args.ephemeral
+        && let Some(path) =
+            storage::migrate_legacy_state_file().context("Failed to migrate the old save")?
+    {
+        eprintln!("Moved your monster to {}.", path.display());
+    }
+
+    if args.command.is_none() {
+        if !args.ephemeral && !storage::state_file().exists() {
+            println!(
+                "No monster yet — run `mons-box-cli feed` to hatch one. Use --help to see available commands."
+            );
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        args.command = Some(SubCommands::Status {
+            compact: false,
+            stats_only: false,
+            output: None,
+            format: None,
+        });
+    }
+
+    if let Some(SubCommands::Restore { backup }) = args.command {
+        return restore(backup);
+    }
+
+    if let Some(SubCommands::Undo) = args.command {
+        if Monster::undo().context("Failed to undo")? {
+            println!("↩️ Undid the last action.");
+        } else {
+            println!("Nothing to undo.");
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut config = Config::load(args.config.as_deref())?;
+    if let Some(hours) = args.max_catch_up {
+        config.max_catch_up_hours = Some(hours);
+    }
+    if let Some(format) = args.save_format {
+        config.save_format = Some(format);
+    }
+    if args.strict {
+        config.strict = Some(true);
+    }
+
+    if let Some(SubCommands::Config {
+        action: ConfigAction::Print,
+    }) = args.command
+    {
+        let source = args.config.unwrap_or_else(config::config_file);
+        eprintln!(
+            "# {}{}",
+            source.display(),
+            if source.exists() {
+                ""
+            } else {
+                " (not found, using defaults)"
+            }
+        );
+        println!("{}", serde_json::to_string_pretty(&config.resolved())?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(SubCommands::Compare { a, b }) = &args.command {
+        let caps = Capabilities::detect().with_overrides(args.ascii, args.no_color);
+        return compare(a, b, &config, &caps);
+    }
+
+    if let Some(SubCommands::HallOfFame) = args.command {
+        let caps = Capabilities::detect().with_overrides(args.ascii, args.no_color);
+        return hall_of_fame(&config, &caps);
+    }
 
-    let mut monster = Monster::load_or_create().context("Failed to load monster state")?;
+    if let Some(SubCommands::List) = args.command {
+        let caps = Capabilities::detect().with_overrides(args.ascii, args.no_color);
+        return list_pets(&config, &caps);
+    }
+
+    if let Some(SubCommands::Switch { name }) = &args.command {
+        return switch_pet(name);
+    }
+
+    if let Some(SubCommands::Prompt) = args.command {
+        // Prompts always capture stdout, so only an explicit --ascii turns emoji off here.
+        let caps = Capabilities::default().with_overrides(args.ascii, true);
+        // Only ever read: a damaged save is left for the next real command to deal with.
+        if let Ok(Some(monster)) = Monster::load_from(&storage::state_file(), &config) {
+            println!("{}", caps.text(&monster.summary()));
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let caps = Capabilities::detect().with_overrides(args.ascii, args.no_color);
+    let mut rng = monster::default_rng();
+    let display_options = DisplayOptions {
+        capabilities: caps,
+        simple_bars: args.simple_bars,
+        // A different pose on each run keeps repeated `status` calls lively.
+        frame: rng.random_range(0..sprite::MAX_FRAMES),
+        quip: rng.random_range(0..usize::MAX),
+        width: args.output_width.unwrap_or(0),
+        warnings: args.warnings,
+    };
+    if let Some(SubCommands::ThemePreview) = args.command {
+        theme_preview(&display_options)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut monster = if args.ephemeral {
+        Monster::from_env_or_default(args.stats.as_deref(), args.species, &config)?
+    } else if args.command.as_ref().is_some_and(SubCommands::is_read_only) {
+        // Looking at the monster should never hatch one as a side effect.
+        match Monster::load(&config).context("Failed to load monster state")? {
+            Some(monster) => {
+                monster.save().context("Failed to save monster state")?;
+                monster
+            }
+            None => {
+                if !matches!(args.command, Some(SubCommands::Notify)) {
+                    println!("No monster yet — run `mons-box-cli feed` to hatch one.");
+                }
+                return Ok(ExitCode::SUCCESS);
+            }
+        }
+    } else {
+        let intro = !args.no_intro && config.intro.unwrap_or(true);
+        Monster::load_or_create(args.species, &config, intro, args.name.as_deref())
+            .context("Failed to load monster state")?
+    };
+
+    let machine_output = matches!(
+        args.command,
+        Some(SubCommands::Status {
+            stats_only: true,
+            ..
+        }) | Some(SubCommands::Status {
+            format: Some(_),
+            ..
+        }) | Some(SubCommands::Notify)
+    );
+    if !args.quiet && !machine_output && !monster.time_away.is_empty() {
+        for line in monster.time_away.summary(&monster.name).lines() {
+            println!("{}", caps.text(line));
+        }
+    }
+
+    let min_health_exit = args.min_health_exit;
+    let below_min_health =
+        |monster: &Monster| min_health_exit.is_some_and(|min| monster.health < min);
 
     match args.command {
-        Some(SubCommands::Feed) => {
-            let result = monster.feed();
-            println!("{}", result);
+        Some(SubCommands::Feed { food }) => {
+            monster.remember_for_undo()?;
+            let result = match food {
+                Some(food) => monster.feed_with(food),
+                None => monster.feed(),
+            };
+            println!("{}", caps.text(&result.to_string()));
             monster.save().context("Failed to save monster state")?;
         }
         Some(SubCommands::Play) => {
+            monster.remember_for_undo()?;
             let result = monster.play();
-            print!("{}", result);
+            println!("{}", caps.text(&result.to_string()));
+            monster.save().context("Failed to save monster state")?;
+        }
+        Some(SubCommands::Pet) => {
+            monster.remember_for_undo()?;
+            let result = monster.pet();
+            println!("{}", caps.text(&result.to_string()));
+            monster.save().context("Failed to save monster state")?;
+        }
+        Some(SubCommands::Bath) => {
+            monster.remember_for_undo()?;
+            let result = monster.bath();
+            println!("{}", caps.text(&result.to_string()));
+            monster.save().context("Failed to save monster state")?;
+        }
+        Some(SubCommands::Medicine) => {
+            monster.remember_for_undo()?;
+            let result = monster.medicine();
+            println!("{}", caps.text(&result.to_string()));
             monster.save().context("Failed to save monster state")?;
         }
         Some(SubCommands::Sleep) => {
-            let result = monster.toggle_sleep();
-            print!("{}", result);
+            monster.remember_for_undo()?;
+            let result = monster.sleep();
+            println!("{}", caps.text(&result.to_string()));
             monster.save().context("Failed to save monster state")?;
         }
-        Some(SubCommands::Status) => {
-            if io::stdout().is_terminal() {
+        Some(SubCommands::Wake) => {
+            monster.remember_for_undo()?;
+            let result = monster.wake();
+            println!("{}", caps.text(&result.to_string()));
+            monster.save().context("Failed to save monster state")?;
+        }
+        Some(SubCommands::Train { skill }) => {
+            monster.remember_for_undo()?;
+            let result = monster.train(skill);
+            println!("{}", caps.text(&result.to_string()));
+            monster.save().context("Failed to save monster state")?;
+        }
+        Some(SubCommands::Rename { new_name }) => {
+            let result = monster.rename(&new_name);
+            println!("{}", caps.text(&result.to_string()));
+            monster.save().context("Failed to save monster state")?;
+        }
+        Some(SubCommands::Status {
+            compact,
+            stats_only,
+            output,
+            format,
+        }) => {
+            if let Some(StatusFormat::Markdown) = format {
+                print!("{}", monster.to_markdown());
+            } else if let Some(StatusFormat::Json) = format {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&StatusReport::from(&monster))?
+                );
+            } else if stats_only {
+                println!("{}", monster.stats_line());
+            } else if let Some(path) = output {
+                let card = card::export_text(&monster, &display_options)
+                    .context("Failed to render monster status")?;
+                fs::write(&path, card).with_context(|| {
+                    format!("Failed to write status card to {}", path.display())
+                })?;
+                println!(
+                    "📝 Saved {}'s status card to {}",
+                    monster.name,
+                    path.display()
+                );
+            } else if compact {
                 let mut stdout = io::stdout().lock();
-                monster
-                    .display(&mut stdout)
+                card::draw_compact(&mut stdout, &monster, &display_options)
+                    .context("Failed to display monster status")?;
+            } else if io::stdout().is_terminal() {
+                let mut stdout = io::stdout().lock();
+                card::draw_status(&mut stdout, &monster, &display_options)
                     .context("Failed to display monster status")?;
             } else {
                 println!("Monster Status:");
                 println!("Name: {}", monster.name);
+                println!("Species: {}", monster.species);
                 println!("Hunger: {}%", monster.hunger);
                 println!("Happiness: {}%", monster.happiness);
                 println!("Energy: {}%", monster.energy);
                 println!("Health: {}%", monster.health);
+                println!("Cleanliness: {}%", monster.cleanliness);
+                println!("Bond: {}%", monster.affection);
+                println!(
+                    "Weight: {} ({})",
+                    monster.weight,
+                    monster.weight_category().label()
+                );
+                println!("Skills: {}", monster.skills_summary());
+                println!("Coins: {}", monster.coins);
+                println!(
+                    "Streak: {} day{}",
+                    monster.care_streak,
+                    if monster.care_streak == 1 { "" } else { "s" }
+                );
+                println!("Sick: {}", if monster.sick { "Yes" } else { "No" });
                 println!("Age: {} hours", monster.age);
+                println!("Stage: {}", monster.life_stage().name());
                 println!(
                     "Status: {}",
                     if monster.is_sleeping {
@@ -74,47 +629,408 @@ fn main() -> Result<ExitCode> {
                     }
                 );
                 println!("Alive: {}", if monster.is_alive { "Yes" } else { "No" });
+                println!("Feeling: {}", monster.describe());
+                if let Some(alert) = monster.current_alert() {
+                    println!("Alert: {}", alert.message(&monster.name));
+                }
             }
+
+            if below_min_health(&monster) {
+                return Ok(ExitCode::from(LOW_HEALTH_EXIT));
+            }
+            return Ok(monster.health_exit_code());
         }
-        Some(SubCommands::Interactive) => {
-            println!("{}", WELCOME_MESSAGE);
-            println!("\nPress Enter to continue...");
+        Some(SubCommands::Diet) => {
+            println!("What {} the {} eats:", monster.name, monster.species);
+            for food in Food::ALL {
+                println!(
+                    "  {}{:<7} {}",
+                    caps.glyph(&format!("{} ", food.emoji()), ""),
+                    food.name(),
+                    monster.species.affinity(food).label()
+                );
+            }
+        }
+        Some(SubCommands::Events) => {
+            if monster.events.is_empty() {
+                println!("Nothing exciting has happened to {} yet.", monster.name);
+            }
+            for event in monster.events.iter().rev() {
+                println!(
+                    "{}  {}",
+                    event.at.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+                    caps.text(&event.kind.describe(&monster.name))
+                );
+            }
+        }
+        Some(SubCommands::Advice) => {
+            if monster.is_alive {
+                println!("{}", need::advice(&monster.needs()));
+            } else {
+                let alert = monster.current_alert().unwrap_or(Alert::Dead);
+                println!("{}", caps.text(&alert.message(&monster.name)));
+            }
+        }
+        Some(SubCommands::Notify) => {
+            if let Some(alert) = monster.current_alert() {
+                println!("{}", caps.text(&alert.message(&monster.name)));
+            }
+        }
+        Some(SubCommands::Remind { every, install }) => {
+            let exe = env::current_exe().context("Failed to locate the mons-box executable")?;
+            let workdir = env::current_dir().context("Failed to read the current directory")?;
+            let scheduler = Scheduler::detect();
+            let snippet = scheduler.snippet(&exe, &workdir, every);
 
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
+            if install {
+                let done = scheduler
+                    .install(&snippet)
+                    .context("Failed to install the reminder")?;
+                println!("⏰ {}", done);
+            } else {
+                println!("{}", snippet);
+                println!();
+                println!("Run `remind --install` to register this automatically.");
+            }
+        }
+        Some(SubCommands::Interactive {
+            exit_on_death,
+            readonly,
+            no_autosave,
+            #[cfg(feature = "dev")]
+            dev,
+        }) => {
+            if !args.no_intro && config.intro.unwrap_or(true) {
+                println!("{}", config.banner.as_deref().unwrap_or(WELCOME_MESSAGE));
+
+                prompt::pause("\nPress Enter to continue...");
+            }
 
-            let mut interactive_mode = InteractiveMode::new(monster);
+            let options = InteractiveOptions {
+                exit_on_death,
+                readonly,
+                no_autosave,
+                #[cfg(feature = "dev")]
+                dev,
+                species: args.species,
+                config: config.clone(),
+                display: display_options,
+            };
+            let mut interactive_mode = InteractiveMode::new(monster, options);
             interactive_mode
                 .run()
                 .context("Failed to run interactive mode")?;
 
-            println!("\n👋 Thanks for playing! Your progress has been saved.");
+            if interactive_mode.has_unsaved_changes() {
+                println!("\n👋 Thanks for playing! Changes since your last save were discarded.");
+            } else {
+                println!("\n👋 Thanks for playing! Your progress has been saved.");
+            }
+            if below_min_health(interactive_mode.monster()) {
+                return Ok(ExitCode::from(LOW_HEALTH_EXIT));
+            }
+            return Ok(ExitCode::SUCCESS);
         }
-        Some(SubCommands::Reset) => {
-            println!(
-                "Are you sure you want to reset? This will delete your current monster. (y/N)"
-            );
-            println!("Reset: ");
-            io::stdout().flush()?;
+        Some(SubCommands::Memorial) => {
+            if monster.is_alive {
+                println!(
+                    "{}",
+                    caps.text(&format!(
+                        "💚 {} is still alive and well. Memorials are for departed monsters.",
+                        monster.name
+                    ))
+                );
+                return Ok(ExitCode::FAILURE);
+            }
 
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
+            for line in monster.epitaph() {
+                println!("{}", caps.text(&line));
+            }
 
-            if input.trim().to_lowercase() == "y" {
+            let archived = monster
+                .lay_to_rest()
+                .context("Failed to archive the memorial")?;
+            println!();
+            println!(
+                "{}",
+                caps.text(&format!(
+                    "🕯️ {} was laid to rest in {}. Run any command to hatch a new monster.",
+                    monster.name,
+                    archived.display()
+                ))
+            );
+        }
+        Some(SubCommands::Reset) => {
+            if prompt::confirm(
+                "Are you sure you want to reset? Your current monster will be moved to a backup.",
+            ) {
                 Monster::reset().context("Failed to reset game")?;
                 println!("✨ Game reset complete! Run any command to create a new monster.");
             } else {
-                print!("🙏 Reset cancelled.");
+                println!("🙏 Reset cancelled.");
             }
         }
-        None => {
-            println!("No command provided. Use --help to see available commands.");
+        Some(SubCommands::Restore { .. }) => unreachable!("restore is handled before loading"),
+        Some(SubCommands::Prompt) => unreachable!("prompt is handled before loading"),
+        Some(SubCommands::Undo) => unreachable!("undo is handled before loading"),
+        Some(SubCommands::Compare { .. }) => unreachable!("compare is handled before loading"),
+        Some(SubCommands::HallOfFame) => {
+            unreachable!("hall-of-fame is handled before loading")
+        }
+        Some(SubCommands::ThemePreview) => {
+            unreachable!("theme-preview is handled before loading")
+        }
+        Some(SubCommands::List | SubCommands::Switch { .. }) => {
+            unreachable!("pets are handled before loading")
         }
+        Some(SubCommands::Config { .. }) => unreachable!("config is handled before loading"),
+        None => unreachable!("a missing command defaults to status"),
     }
 
+    if below_min_health(&monster) {
+        return Ok(ExitCode::from(LOW_HEALTH_EXIT));
+    }
     Ok(ExitCode::SUCCESS)
 }
 
+/// Renders synthetic monsters: each species in each mood, then a full card per bar style.
+fn theme_preview(options: &DisplayOptions) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    // Setting every stat to the same level gives a care score, and so a mood, of that level.
+    let moods = [95, 80, 65, 50, 35, 20, 5];
+    for species in Species::ALL {
+        for level in moods {
+            let mut monster = Monster::new(species.name().to_string(), species);
+            monster.hunger = 100 - level;
+            monster.happiness = level;
+            monster.energy = level;
+            monster.health = level;
+            preview_sprite(&mut stdout, &monster, options)?;
+        }
+
+        let mut sleeping = Monster::new(species.name().to_string(), species);
+        sleeping.is_sleeping = true;
+        preview_sprite(&mut stdout, &sleeping, options)?;
+
+        let mut dead = Monster::new(species.name().to_string(), species);
+        dead.is_alive = false;
+        preview_sprite(&mut stdout, &dead, options)?;
+    }
+
+    for simple_bars in [false, true] {
+        let style = if simple_bars { "simple" } else { "gradient" };
+        writeln!(stdout, "── {} bars ──", style)?;
+        let mut monster = Monster::new("Preview".to_string(), Species::default());
+        monster.happiness = 45;
+        monster.energy = 15;
+        card::draw_status(
+            &mut stdout,
+            &monster,
+            &DisplayOptions {
+                simple_bars,
+                ..*options
+            },
+        )
+        .context("Failed to render the preview")?;
+        writeln!(stdout)?;
+    }
+
+    Ok(())
+}
+
+fn preview_sprite(
+    stdout: &mut impl Write,
+    monster: &Monster,
+    options: &DisplayOptions,
+) -> Result<()> {
+    let (_, mood) = monster.get_mood();
+    writeln!(stdout, "── {} · {} ──", monster.species, mood)?;
+    card::draw_sprite(stdout, monster, options).context("Failed to render the preview")?;
+    writeln!(stdout)?;
+
+    Ok(())
+}
+
+fn hall_of_fame(config: &Config, caps: &Capabilities) -> Result<ExitCode> {
+    let memorials = Monster::memorials().context("Failed to read the memorials")?;
+    let active = Monster::load(config).context("Failed to load monster state")?;
+
+    let entries = hall_of_fame::rank(&memorials, active.as_ref());
+    if entries.is_empty() {
+        println!(
+            "The hall of fame is empty — run `mons-box-cli feed` to hatch your first monster."
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    println!("{}", caps.text("🏆 Hall of Fame"));
+    for (place, entry) in entries.iter().enumerate() {
+        let fate = match entry.died_at {
+            Some(died_at) => format!(
+                "died {}{}",
+                died_at.with_timezone(&Local).format("%Y-%m-%d"),
+                entry
+                    .cause_of_death
+                    .map(|cause| format!(" of {}", cause.label()))
+                    .unwrap_or_default()
+            ),
+            None => "still going".to_string(),
+        };
+        println!(
+            "{:>3}. {} the {} — {} hour{}, care {}%, {}",
+            place + 1,
+            entry.name,
+            entry.species,
+            entry.lifespan,
+            if entry.lifespan == 1 { "" } else { "s" },
+            entry.care_score,
+            fate
+        );
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn list_pets(config: &Config, caps: &Capabilities) -> Result<ExitCode> {
+    let pets = Monster::pets(config).context("Failed to list pets")?;
+    if pets.is_empty() {
+        println!("No pets yet — run `mons-box-cli --pet NAME feed` to hatch one.");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    println!("{}", caps.text("🐾 Your pets"));
+    for (slot, monster) in pets {
+        let (emoji, mood) = monster.get_mood();
+        println!(
+            "  {:<12} {} the {} — {}{}, {}",
+            slot.as_deref().unwrap_or("(main)"),
+            monster.name,
+            monster.species,
+            caps.glyph(&format!("{} ", emoji), ""),
+            mood,
+            if monster.is_alive { "alive" } else { "dead" }
+        );
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn switch_pet(name: &str) -> Result<ExitCode> {
+    let Some(path) = storage::pet_state_file(name).filter(|path| path.exists()) else {
+        eprintln!(
+            "No pet called {} yet — run `mons-box-cli --pet {} feed` to hatch one.",
+            name,
+            storage::pet_slot(name)
+        );
+        return Ok(ExitCode::FAILURE);
+    };
+
+    println!(
+        "🐾 Found {}. Pass --pet {} to look after them.",
+        path.display(),
+        storage::pet_slot(name)
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn compare(a: &Path, b: &Path, config: &Config, caps: &Capabilities) -> Result<ExitCode> {
+    let load = |path: &Path| {
+        Monster::load_from(path, config)
+            .with_context(|| format!("Failed to load monster from {}", path.display()))
+    };
+
+    let (a_monster, b_monster) = match (load(a)?, load(b)?) {
+        (Some(a), Some(b)) => (a, b),
+        (a_monster, b_monster) => {
+            for (path, monster) in [(a, a_monster), (b, b_monster)] {
+                if monster.is_none() {
+                    eprintln!("No monster found at {}.", path.display());
+                }
+            }
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    let header = |monster: &Monster| {
+        if monster.is_alive {
+            monster.name.clone()
+        } else {
+            format!("{} (dead)", monster.name)
+        }
+    };
+    let comparison = comparison::compare(&a_monster, &b_monster);
+
+    println!(
+        "{:<12} {:>16} {:>16}",
+        "",
+        header(&a_monster),
+        header(&b_monster)
+    );
+    for row in &comparison.rows {
+        let mark = |side| if row.better() == Some(side) { "*" } else { " " };
+        println!(
+            "{:<12} {:>15}{} {:>15}{}",
+            row.label,
+            row.a,
+            mark(Side::A),
+            row.b,
+            mark(Side::B)
+        );
+    }
+
+    println!();
+    let verdict = match comparison.healthier {
+        Some(Side::A) => format!("💪 {} is in better shape.", a_monster.name),
+        Some(Side::B) => format!("💪 {} is in better shape.", b_monster.name),
+        None => "🤝 Neither is in better shape.".to_string(),
+    };
+    println!("{}", caps.text(&verdict));
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn restore(backup: Option<String>) -> Result<ExitCode> {
+    let backups = Monster::backups().context("Failed to list backups")?;
+
+    let Some(choice) = backup else {
+        if backups.is_empty() {
+            println!("No backups yet. A backup is made every time you reset.");
+        } else {
+            println!("Available backups (newest first):");
+            for (index, backup) in backups.iter().enumerate() {
+                println!("  {}. {}", index + 1, backup.display());
+            }
+            println!("Run `restore <number>` to bring one back.");
+        }
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    let selected = match choice.parse::<usize>() {
+        Ok(number) => number.checked_sub(1).and_then(|index| backups.get(index)),
+        Err(_) => backups.iter().find(|backup| {
+            backup.file_name().and_then(|name| name.to_str()) == Some(choice.as_str())
+        }),
+    };
+
+    let Some(selected) = selected else {
+        eprintln!(
+            "No backup matches '{}'. Run `restore` to list them.",
+            choice
+        );
+        return Ok(ExitCode::FAILURE);
+    };
+
+    Monster::restore(selected).context("Failed to restore backup")?;
+    println!("♻️ Restored {}", selected.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Exit code for `--min-health-exit`, clear of the 0-2 health bands.
+const LOW_HEALTH_EXIT: u8 = 3;
+
 const WELCOME_MESSAGE: &str = "r#
     🎮 Welcome to CLI Mons Box! 🎮
 
@@ -0,0 +1,151 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result, bail};
+
+const CRON_MARKER: &str = "# mons-box reminder";
+const LAUNCHD_LABEL: &str = "com.mons-box.notify";
+const TASK_NAME: &str = "mons-box-notify";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheduler {
+    Cron,
+    Launchd,
+    TaskScheduler,
+}
+
+impl Scheduler {
+    pub fn detect() -> Self {
+        if cfg!(target_os = "macos") {
+            Scheduler::Launchd
+        } else if cfg!(windows) {
+            Scheduler::TaskScheduler
+        } else {
+            Scheduler::Cron
+        }
+    }
+
+    /// The scheduler entry that runs `<exe> notify` from `workdir` every `minutes`.
+    pub fn snippet(&self, exe: &Path, workdir: &Path, minutes: u32) -> String {
+        let minutes = minutes.max(1);
+        let exe = exe.display();
+        let workdir = workdir.display();
+
+        match self {
+            Scheduler::Cron => {
+                let schedule = if minutes < 60 {
+                    format!("*/{} * * * *", minutes)
+                } else {
+                    format!("0 */{} * * *", (minutes / 60).min(23))
+                };
+                format!(
+                    "{} cd '{}' && '{}' notify {}",
+                    schedule, workdir, exe, CRON_MARKER
+                )
+            }
+            Scheduler::Launchd => format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{}</string>
+        <string>notify</string>
+    </array>
+    <key>WorkingDirectory</key>
+    <string>{}</string>
+    <key>StartInterval</key>
+    <integer>{}</integer>
+</dict>
+</plist>
+"#,
+                LAUNCHD_LABEL,
+                exe,
+                workdir,
+                minutes * 60
+            ),
+            Scheduler::TaskScheduler => format!(
+                r#"schtasks /Create /F /SC MINUTE /MO {} /TN {} /TR "cmd /c cd /d \"{}\" && \"{}\" notify""#,
+                minutes, TASK_NAME, workdir, exe
+            ),
+        }
+    }
+
+    /// Registers the snippet with the platform scheduler and describes what was done.
+    pub fn install(&self, snippet: &str) -> Result<String> {
+        match self {
+            Scheduler::Cron => install_cron(snippet),
+            Scheduler::Launchd => install_launchd(snippet),
+            Scheduler::TaskScheduler => {
+                let status = Command::new("cmd")
+                    .args(["/C", snippet])
+                    .status()
+                    .context("Failed to run schtasks")?;
+                if !status.success() {
+                    bail!("schtasks exited with {}", status);
+                }
+                Ok(format!("Created scheduled task {}", TASK_NAME))
+            }
+        }
+    }
+}
+
+fn install_cron(snippet: &str) -> Result<String> {
+    let existing = Command::new("crontab")
+        .arg("-l")
+        .stderr(Stdio::null())
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default();
+
+    let mut lines: Vec<&str> = existing
+        .lines()
+        .filter(|line| !line.ends_with(CRON_MARKER))
+        .collect();
+    lines.push(snippet);
+
+    let mut child = Command::new("crontab")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .spawn()
+        .context("Failed to run crontab")?;
+    child
+        .stdin
+        .take()
+        .context("Failed to open crontab input")?
+        .write_all(format!("{}\n", lines.join("\n")).as_bytes())?;
+
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("crontab exited with {}", status);
+    }
+
+    Ok("Added the reminder to your crontab".to_string())
+}
+
+fn install_launchd(plist: &str) -> Result<String> {
+    let home = env::var_os("HOME").context("HOME is not set")?;
+    let dir = PathBuf::from(home).join("Library/LaunchAgents");
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let path = dir.join(format!("{}.plist", LAUNCHD_LABEL));
+    fs::write(&path, plist).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let status = Command::new("launchctl")
+        .arg("load")
+        .arg(&path)
+        .status()
+        .context("Failed to run launchctl")?;
+    if !status.success() {
+        bail!("launchctl exited with {}", status);
+    }
+
+    Ok(format!("Installed {}", path.display()))
+}
@@ -0,0 +1,659 @@
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use crossterm::style::Color;
+
+use crate::{
+    app_state::{
+        action::Action,
+        death::CauseOfDeath,
+        life_stage::LifeStage,
+        monster::{self, MAX_STAT, Monster},
+        sprite,
+    },
+    render::options::{DisplayOptions, WarningLevel},
+    terminal::boxes,
+};
+
+/// The title box and the monster itself, without any stats.
+pub fn draw_sprite(
+    stdout: &mut impl Write,
+    monster: &Monster,
+    options: &DisplayOptions,
+) -> Result<()> {
+    let caps = &options.capabilities;
+    let (emoji, _) = monster.get_mood();
+    let outline = monster.skin.outline();
+    let stage = monster.life_stage();
+
+    let width = options.box_width();
+    let icon = caps.glyph("🐲", "<>");
+
+    write!(stdout, "{}\r\n", boxes::top(width))?;
+    write!(
+        stdout,
+        "{}\r\n",
+        boxes::centered(&format!("{0}  Monster Status  {0}", icon), width)
+    )?;
+    write!(stdout, "{}\r\n", boxes::bottom(width))?;
+    writeln!(stdout)?;
+
+    if let Some(quip) = monster.quip(options.quip) {
+        draw_speech_bubble(stdout, &caps.text(quip))?;
+    }
+
+    if !monster.is_alive {
+        let skull = caps.glyph("💀", "XX");
+        write!(stdout, "        {0}     {0}\r\n", skull)?;
+        write!(stdout, "          ╲   ╱\r\n")?;
+        write!(stdout, "           ╲ ╱\r\n")?;
+        write!(stdout, "         ───┴───\r\n")?;
+        write!(stdout, "        {0} R.I.P {0}\r\n", skull)?;
+    } else if stage == LifeStage::Egg {
+        for line in sprite::HATCHING[0] {
+            write!(stdout, "      {}\r\n", line)?;
+        }
+        write!(
+            stdout,
+            "        {}  {}\r\n",
+            caps.glyph("🥚", "  "),
+            monster.name
+        )?;
+    } else if monster.is_sleeping {
+        write!(
+            stdout,
+            "          {}\r\n",
+            sprite::frame(&sprite::SNORES, options.frame)
+        )?;
+        if let Some(topper) = stage.topper() {
+            write!(stdout, "          {}\r\n", topper)?;
+        }
+        write!(stdout, "        {}\r\n", outline.head)?;
+        write!(
+            stdout,
+            "       {}  - -  {}\r\n",
+            outline.left, outline.right
+        )?;
+        write!(
+            stdout,
+            "      {}    ω    {}\r\n",
+            outline.left, outline.right
+        )?;
+        write!(stdout, "     {}\r\n", outline.base)?;
+        write!(stdout, "        {}\r\n", caps.glyph("😴💤💤", "zZzZzZ"))?;
+    } else {
+        let face = sprite::frame(sprite::faces(emoji), options.frame);
+
+        if let Some(topper) = stage.topper() {
+            write!(stdout, "          {}\r\n", topper)?;
+        }
+        write!(stdout, "        {}\r\n", outline.head)?;
+        write!(
+            stdout,
+            "       {}  {} {}  {}\r\n",
+            outline.left, face.0, face.2, outline.right
+        )?;
+        write!(
+            stdout,
+            "      {}    {}    {}\r\n",
+            outline.left, face.1, outline.right
+        )?;
+        write!(stdout, "     {}\r\n", outline.base)?;
+        write!(
+            stdout,
+            "        {}  {}\r\n",
+            caps.glyph(emoji, "  "),
+            monster.name
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn draw_status(
+    stdout: &mut impl Write,
+    monster: &Monster,
+    options: &DisplayOptions,
+) -> Result<()> {
+    let caps = &options.capabilities;
+    let (_, mood) = monster.get_mood();
+
+    draw_sprite(stdout, monster, options)?;
+
+    writeln!(stdout)?;
+    write!(stdout, "{}Stats:\r\n", caps.glyph("📊 ", ""))?;
+
+    let bars = [
+        (
+            format!("{}Hunger", caps.glyph("🍽️ ", "")),
+            MAX_STAT - monster.hunger,
+            Color::Green,
+            Color::Red,
+        ),
+        (
+            format!("{}Happiness", caps.glyph("😊 ", "")),
+            monster.happiness,
+            Color::Yellow,
+            Color::Grey,
+        ),
+        (
+            format!("{}Health", caps.glyph("💖 ", "")),
+            monster.health,
+            Color::Red,
+            Color::DarkRed,
+        ),
+        (
+            format!("{}Energy", caps.glyph("⚡ ", "")),
+            monster.energy,
+            Color::Cyan,
+            Color::DarkCyan,
+        ),
+        (
+            format!("{}Cleanliness", caps.glyph("🧼 ", "")),
+            monster.cleanliness,
+            Color::Blue,
+            Color::DarkBlue,
+        ),
+        (
+            format!("{}Bond", caps.glyph("❤️ ", "")),
+            monster.affection,
+            Color::Magenta,
+            Color::DarkMagenta,
+        ),
+    ];
+    // Emoji differ in width, so pad every label to the widest to line the bars up.
+    let label_width = bars
+        .iter()
+        .map(|(label, ..)| boxes::display_width(label))
+        .max()
+        .unwrap_or(0);
+    for (label, value, good_color, bad_color) in &bars {
+        let padding = " ".repeat(label_width - boxes::display_width(label));
+        draw_status_bar(
+            stdout,
+            options,
+            &format!("{}:{}", label, padding),
+            *value,
+            *good_color,
+            *bad_color,
+        )?;
+    }
+
+    writeln!(stdout)?;
+    write!(stdout, "{}Info:", caps.glyph("📈 ", ""))?;
+    write!(stdout, "   Age: {} hours old\r\n", monster.age)?;
+    write!(stdout, "   Species: {}\r\n", monster.species)?;
+    write!(stdout, "   Mood: {}\r\n", mood)?;
+    write!(stdout, "   Feeling: {}\r\n", monster.describe())?;
+    write!(
+        stdout,
+        "   Weight: {}\r\n",
+        caps.text(monster.weight_category().label())
+    )?;
+    write!(stdout, "   Skills: {}\r\n", monster.skills_summary())?;
+    write!(stdout, "   Coins: {}\r\n", monster.coins)?;
+    if monster.care_streak > 0 {
+        write!(
+            stdout,
+            "   {}{}-day streak\r\n",
+            caps.glyph("🔥 ", "Streak: "),
+            monster.care_streak
+        )?;
+    }
+    let cooldowns: Vec<String> = [(Action::Feed, "feed"), (Action::Play, "play")]
+        .into_iter()
+        .filter_map(|(action, label)| {
+            let remaining = monster.cooldown_remaining(action)?;
+            Some(format!("{} in {}", label, monster::format_wait(remaining)))
+        })
+        .collect();
+    if !cooldowns.is_empty() {
+        write!(
+            stdout,
+            "   {}{}\r\n",
+            caps.glyph("⏳ Ready to ", "Ready to "),
+            cooldowns.join(", ")
+        )?;
+    }
+    write!(
+        stdout,
+        "   Status: {}{}\r\n",
+        if monster.is_sleeping {
+            caps.glyph("😴 Sleeping", "Sleeping")
+        } else {
+            caps.glyph("👁️ Awake", "Awake")
+        },
+        if monster.sick {
+            caps.glyph(", 🤒 Sick", ", Sick")
+        } else {
+            ""
+        }
+    )?;
+
+    let all_warnings = options.warnings == WarningLevel::All;
+    if !monster.is_alive {
+        if options.warnings == WarningLevel::Off {
+            stdout.flush()?;
+            return Ok(());
+        }
+        caps.set_color(stdout, Color::Red)?;
+        writeln!(stdout)?;
+        if monster.cause_of_death == Some(CauseOfDeath::OldAge) {
+            write!(
+                stdout,
+                "{}Your pet lived a full life and passed away peacefully. You can start over with a new pet.\r\n",
+                caps.glyph("🕊️ ", "")
+            )?;
+        } else {
+            write!(
+                stdout,
+                "{}Your pet has died. You can start over with a new pet.\r\n",
+                caps.glyph("💀 ", "")
+            )?;
+        }
+        caps.reset_color(stdout)?;
+    } else {
+        writeln!(stdout)?;
+        write!(
+            stdout,
+            "{}Commands: feed, play, sleep, status, interactive\r\n",
+            caps.glyph("🎮 ", "")
+        )?;
+
+        let warn = caps.glyph("⚠️  ", "! ");
+        if all_warnings && monster.is_hungry() {
+            caps.set_color(stdout, Color::Red)?;
+            write!(stdout, "{}{} is very hungry!\r\n", warn, monster.name)?;
+            caps.reset_color(stdout)?;
+        }
+        if all_warnings && monster.is_sad() {
+            caps.set_color(stdout, Color::Yellow)?;
+            write!(
+                stdout,
+                "{}{} looks sad. Try playing with them!\r\n",
+                warn, monster.name
+            )?;
+            caps.reset_color(stdout)?;
+        }
+        if all_warnings && monster.is_exhausted() {
+            caps.set_color(stdout, Color::Cyan)?;
+            write!(
+                stdout,
+                "{}{} is exhausted. Let them sleep!\r\n",
+                warn, monster.name
+            )?;
+            caps.reset_color(stdout)?;
+        }
+        let unwell = match options.warnings {
+            WarningLevel::Off => false,
+            WarningLevel::Critical => monster.health < monster.thresholds.critical_health,
+            WarningLevel::All => monster.is_unwell(),
+        };
+        if unwell {
+            caps.set_color(stdout, Color::Red)?;
+            write!(
+                stdout,
+                "{}{} doesn't look well. Take better care!\r\n",
+                warn, monster.name
+            )?;
+            caps.reset_color(stdout)?;
+        }
+    }
+
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Renders the status card as plain text with no color codes, for saving or sharing.
+pub fn export_text(monster: &Monster, options: &DisplayOptions) -> Result<String> {
+    let mut options = *options;
+    options.capabilities.color = false;
+
+    let mut buffer = Vec::new();
+    draw_status(&mut buffer, monster, &options)?;
+
+    let text = String::from_utf8(buffer).context("Rendered status is not valid UTF-8")?;
+    Ok(text.replace("\r\n", "\n"))
+}
+
+impl Monster {
+    /// The full status card exactly as a color-free, emoji-capable terminal would show it.
+    /// Handy for comparing layouts in tests and bug reports.
+    pub fn render_to_string(&self) -> String {
+        export_text(self, &DisplayOptions::default()).expect("rendering into memory cannot fail")
+    }
+}
+
+pub fn draw_compact(
+    out: &mut impl Write,
+    monster: &Monster,
+    options: &DisplayOptions,
+) -> Result<()> {
+    let caps = &options.capabilities;
+    let (emoji, mood) = monster.get_mood();
+
+    writeln!(
+        out,
+        "{} {} H:{} J:{} E:{} {}:{}",
+        monster.name,
+        caps.glyph(emoji, mood),
+        monster.hunger,
+        monster.happiness,
+        monster.energy,
+        caps.glyph("❤", "HP"),
+        monster.health
+    )?;
+
+    if let Some(alert) = monster.current_alert() {
+        writeln!(out, "{}", caps.text(&alert.message(&monster.name)))?;
+    }
+
+    out.flush()?;
+    Ok(())
+}
+
+fn draw_status_bar(
+    stdout: &mut impl Write,
+    options: &DisplayOptions,
+    label: &str,
+    value: u8,
+    good_color: Color,
+    bad_color: Color,
+) -> Result<()> {
+    let bar_width = options.bar_width();
+    let filled = (value as usize * bar_width) / MAX_STAT as usize;
+    let empty = bar_width - filled;
+
+    let caps = &options.capabilities;
+
+    write!(stdout, "   {} [", label)?;
+
+    if options.simple_bars {
+        let color = if value > 60 { good_color } else { bad_color };
+        caps.set_color(stdout, color)?;
+
+        for _ in 0..filled {
+            write!(stdout, "█")?;
+        }
+    } else {
+        for cell in 0..filled {
+            caps.set_color(stdout, gradient_color(cell, bar_width))?;
+            write!(stdout, "█")?;
+        }
+    }
+
+    caps.set_color(stdout, Color::DarkGrey)?;
+    for _ in 0..empty {
+        write!(stdout, "░")?;
+    }
+
+    caps.reset_color(stdout)?;
+    write!(stdout, "] {}%\r\n", value)?;
+
+    Ok(())
+}
+
+/// A bubble with its tail over the left corner of the sprite's head, at column 8.
+fn draw_speech_bubble(stdout: &mut impl Write, text: &str) -> Result<()> {
+    let width = boxes::display_width(text) + 4;
+
+    write!(stdout, "     {}\r\n", boxes::top(width))?;
+    write!(
+        stdout,
+        "     {}\r\n",
+        boxes::row(&format!(" {} ", text), width)
+    )?;
+    write!(stdout, "     ╰──┬{}╯\r\n", "─".repeat(width - 5))?;
+
+    Ok(())
+}
+
+/// Colors a bar cell by how far along the bar it sits, from red at the empty end to green at
+/// the full end, so the length of the bar reads at a glance.
+fn gradient_color(cell: usize, bar_width: usize) -> Color {
+    match cell * 4 / bar_width {
+        0 => Color::Red,
+        1 => Color::DarkYellow,
+        2 => Color::Yellow,
+        _ => Color::Green,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::*;
+    use crate::{
+        app_state::{config::Config, death::CauseOfDeath, species::Species},
+        terminal::capabilities::Capabilities,
+    };
+
+    /// Compares `actual` with `src/render/snapshots/<name>.txt`. After an intended change,
+    /// run the tests with `UPDATE_SNAPSHOTS=1` to rewrite the files, and review the diff.
+    fn assert_snapshot(name: &str, actual: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/render/snapshots")
+            .join(format!("{}.txt", name));
+        if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            fs::write(&path, actual).unwrap();
+            return;
+        }
+
+        let expected = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Couldn't read {}: {}", path.display(), err));
+        assert_eq!(
+            actual, expected,
+            "{} changed; rerun with UPDATE_SNAPSHOTS=1 if that was intended",
+            name
+        );
+    }
+
+    /// A day-old Blob that is doing well.
+    fn happy() -> Monster {
+        let mut monster = Monster::new("Testy".to_string(), Species::Blob);
+        monster.age = 30;
+        monster.happiness = 90;
+        monster
+    }
+
+    fn plain() -> DisplayOptions {
+        DisplayOptions {
+            capabilities: Capabilities {
+                color: false,
+                emoji: true,
+            },
+            ..DisplayOptions::default()
+        }
+    }
+
+    #[test]
+    fn awake_and_happy() {
+        let mut out = Vec::new();
+        draw_status(&mut out, &happy(), &plain()).unwrap();
+
+        assert_snapshot("awake_happy", &String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn a_custom_threshold_changes_which_warnings_appear() {
+        let mut monster = happy();
+        monster.hunger = 60;
+        let render = |monster: &Monster| {
+            let mut out = Vec::new();
+            draw_status(&mut out, monster, &plain()).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert!(!render(&monster).contains("is very hungry"));
+
+        monster.configure(&Config {
+            hunger_warn: Some(55),
+            ..Config::default()
+        });
+        assert!(render(&monster).contains("Testy is very hungry!"));
+    }
+
+    #[test]
+    fn render_to_string_matches_the_export() {
+        let monster = happy();
+        assert_eq!(
+            monster.render_to_string(),
+            export_text(&monster, &DisplayOptions::default()).unwrap()
+        );
+        assert!(!monster.render_to_string().contains('\x1b'));
+    }
+
+    #[test]
+    fn a_fixed_monster_renders_the_same_card() {
+        assert_snapshot("fixed_stats", &happy().render_to_string());
+    }
+
+    #[test]
+    fn sleeping() {
+        let mut monster = happy();
+        monster.is_sleeping = true;
+        monster.energy = 30;
+
+        assert_snapshot("sleeping", &monster.render_to_string());
+    }
+
+    #[test]
+    fn dead() {
+        let mut monster = happy();
+        monster.is_alive = false;
+        monster.health = 0;
+        monster.hunger = 100;
+        monster.died_at = Some(monster.updated_at);
+        monster.cause_of_death = Some(CauseOfDeath::Neglect);
+
+        assert_snapshot("dead", &monster.render_to_string());
+    }
+
+    #[test]
+    fn sad_and_hungry() {
+        let mut monster = happy();
+        monster.happiness = 15;
+        monster.hunger = 85;
+
+        assert_snapshot("sad_hungry", &monster.render_to_string());
+    }
+
+    #[test]
+    fn exhausted() {
+        let mut monster = happy();
+        monster.energy = 5;
+
+        assert_snapshot("exhausted", &monster.render_to_string());
+    }
+
+    #[test]
+    fn ascii_mode() {
+        let options = DisplayOptions {
+            capabilities: Capabilities {
+                color: false,
+                emoji: false,
+            },
+            ..DisplayOptions::default()
+        };
+
+        assert_snapshot("ascii", &export_text(&happy(), &options).unwrap());
+    }
+
+    /// Where each stat bar's `[` sits: its byte offset and its display column.
+    fn bar_starts(card: &str) -> Vec<(usize, usize)> {
+        card.lines()
+            .filter(|line| line.ends_with('%') && line.contains("] "))
+            .map(|line| {
+                let offset = line.find('[').unwrap();
+                (offset, boxes::display_width(&line[..offset]))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ascii_bars_start_at_the_same_offset() {
+        let options = DisplayOptions {
+            capabilities: Capabilities {
+                color: false,
+                emoji: false,
+            },
+            ..DisplayOptions::default()
+        };
+        let starts = bar_starts(&export_text(&happy(), &options).unwrap());
+
+        assert_eq!(starts.len(), 6);
+        assert!(
+            starts.iter().all(|&start| start == starts[0]),
+            "{:?}",
+            starts
+        );
+    }
+
+    #[test]
+    fn emoji_bars_start_at_the_same_column() {
+        let starts = bar_starts(&happy().render_to_string());
+
+        assert_eq!(starts.len(), 6);
+        assert!(
+            starts.iter().all(|&(_, column)| column == starts[0].1),
+            "{:?}",
+            starts
+        );
+    }
+
+    /// Testy in trouble on every front: hungry, sad, exhausted and low on health.
+    fn struggling() -> Monster {
+        let mut monster = happy();
+        monster.hunger = 90;
+        monster.happiness = 10;
+        monster.energy = 5;
+        monster.health = 10;
+        monster
+    }
+
+    fn warnings(monster: &Monster, level: WarningLevel) -> Vec<String> {
+        let options = DisplayOptions {
+            warnings: level,
+            ..plain()
+        };
+        export_text(monster, &options)
+            .unwrap()
+            .lines()
+            .filter(|line| line.starts_with("⚠️"))
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn critical_warnings_keep_only_the_health_one() {
+        assert_eq!(warnings(&struggling(), WarningLevel::All).len(), 4);
+        assert_eq!(
+            warnings(&struggling(), WarningLevel::Critical),
+            ["⚠️  Testy doesn't look well. Take better care!"]
+        );
+        assert!(warnings(&struggling(), WarningLevel::Off).is_empty());
+    }
+
+    #[test]
+    fn critical_warnings_show_nothing_for_a_healthy_monster() {
+        let mut monster = struggling();
+        monster.health = 90;
+
+        assert!(warnings(&monster, WarningLevel::Critical).is_empty());
+    }
+
+    #[test]
+    fn colored_output_stays_byte_identical() {
+        let options = DisplayOptions {
+            capabilities: Capabilities {
+                color: true,
+                emoji: true,
+            },
+            ..DisplayOptions::default()
+        };
+        let mut out = Vec::new();
+        draw_status(&mut out, &happy(), &options).unwrap();
+
+        assert_snapshot("awake_happy_color", &String::from_utf8(out).unwrap());
+    }
+}
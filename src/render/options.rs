@@ -0,0 +1,46 @@
+use clap::ValueEnum;
+
+use crate::terminal::{boxes, capabilities::Capabilities};
+
+/// Which warnings the status screen prints under the stats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum WarningLevel {
+    /// None at all
+    Off,
+    /// Only death and critically low health
+    Critical,
+    /// Every warning
+    #[default]
+    All,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayOptions {
+    pub capabilities: Capabilities,
+    /// Color whole bars by a single good/bad threshold instead of a gradient.
+    pub simple_bars: bool,
+    /// Which animation frame of the sprite to draw. Any value is valid; `0` is the resting
+    /// pose, so the default output is deterministic.
+    pub frame: usize,
+    /// Which of the mood's lines the speech bubble shows, like `frame`.
+    pub quip: usize,
+    /// Width of boxes, borders included, from `--output-width`. `0` keeps the default;
+    /// anything narrower than `boxes::MIN_WIDTH` is widened to it.
+    pub width: usize,
+    pub warnings: WarningLevel,
+}
+
+impl DisplayOptions {
+    pub fn box_width(&self) -> usize {
+        if self.width == 0 {
+            boxes::DEFAULT_WIDTH
+        } else {
+            self.width.max(boxes::MIN_WIDTH)
+        }
+    }
+
+    /// Bars grow and shrink with the boxes so the two stay lined up.
+    pub fn bar_width(&self) -> usize {
+        20 + self.box_width() - boxes::DEFAULT_WIDTH
+    }
+}
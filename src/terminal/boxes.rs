@@ -0,0 +1,41 @@
+use unicode_width::UnicodeWidthStr;
+
+/// Width of every box, borders included, unless `--output-width` asks for more.
+pub const DEFAULT_WIDTH: usize = 35;
+/// Narrowest box that still fits the longest line any box holds.
+pub const MIN_WIDTH: usize = DEFAULT_WIDTH;
+
+pub fn top(width: usize) -> String {
+    format!("╭{}╮", "─".repeat(width - 2))
+}
+
+pub fn divider(width: usize) -> String {
+    format!("├{}┤", "─".repeat(width - 2))
+}
+
+pub fn bottom(width: usize) -> String {
+    format!("╰{}╯", "─".repeat(width - 2))
+}
+
+/// A left-aligned line of text between the side borders.
+pub fn row(text: &str, width: usize) -> String {
+    let padding = (width - 2).saturating_sub(display_width(text));
+    format!("│{}{}│", text, " ".repeat(padding))
+}
+
+/// A centered line of text between the side borders; odd padding goes on the right.
+pub fn centered(text: &str, width: usize) -> String {
+    let padding = (width - 2).saturating_sub(display_width(text));
+    let left = padding / 2;
+    format!(
+        "│{}{}{}│",
+        " ".repeat(left),
+        text,
+        " ".repeat(padding - left)
+    )
+}
+
+/// Terminal columns `text` takes up, counting emoji as two.
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
@@ -0,0 +1,163 @@
+use std::{
+    borrow::Cow,
+    env,
+    io::{self, IsTerminal, Write},
+};
+
+use anyhow::Result;
+use crossterm::{
+    QueueableCommand,
+    style::{Color, ResetColor, SetForegroundColor},
+};
+
+/// What the output terminal can render, decided once and shared by every renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    pub color: bool,
+    pub emoji: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            color: true,
+            emoji: true,
+        }
+    }
+}
+
+impl Capabilities {
+    pub fn detect() -> Self {
+        Self::from_env(|key| env::var(key).ok(), io::stdout().is_terminal())
+    }
+
+    /// Decides capabilities from `TERM`, `NO_COLOR` and `COLORTERM` and whether stdout is a
+    /// terminal. `var` looks up an environment variable so the decision can be tested.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>, is_terminal: bool) -> Self {
+        let term = var("TERM").unwrap_or_default();
+        let dumb_term = term == "dumb" || (term.is_empty() && !cfg!(windows));
+        let no_color = var("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let color_term = var("COLORTERM").is_some_and(|value| !value.is_empty());
+
+        Self {
+            color: is_terminal && !no_color && (!dumb_term || color_term),
+            emoji: is_terminal && !dumb_term && term != "linux",
+        }
+    }
+
+    pub fn with_overrides(mut self, ascii: bool, no_color: bool) -> Self {
+        if ascii {
+            self.emoji = false;
+        }
+        if no_color {
+            self.color = false;
+        }
+        self
+    }
+
+    pub fn glyph<'a>(&self, emoji: &'a str, ascii: &'a str) -> &'a str {
+        if self.emoji { emoji } else { ascii }
+    }
+
+    /// Strips emoji from free text when the terminal can't show them.
+    pub fn text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.emoji {
+            return Cow::Borrowed(text);
+        }
+
+        let stripped: String = text.chars().filter(|c| !is_emoji(*c)).collect();
+        Cow::Owned(stripped.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
+    pub fn set_color(&self, out: &mut impl Write, color: Color) -> Result<()> {
+        if self.color {
+            out.queue(SetForegroundColor(color))?;
+        }
+        Ok(())
+    }
+
+    pub fn reset_color(&self, out: &mut impl Write) -> Result<()> {
+        if self.color {
+            out.queue(ResetColor)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0xFE0F | 0x200D
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(vars: &[(&str, &str)], is_terminal: bool) -> Capabilities {
+        Capabilities::from_env(
+            |key| {
+                vars.iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value.to_string())
+            },
+            is_terminal,
+        )
+    }
+
+    #[test]
+    fn a_modern_terminal_gets_everything() {
+        let caps = detect(&[("TERM", "xterm-256color")], true);
+        assert!(caps.color);
+        assert!(caps.emoji);
+    }
+
+    #[test]
+    fn no_color_turns_off_color_only() {
+        let caps = detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")], true);
+        assert!(!caps.color);
+        assert!(caps.emoji);
+    }
+
+    #[test]
+    fn an_empty_no_color_is_ignored() {
+        let caps = detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "")], true);
+        assert!(caps.color);
+    }
+
+    #[test]
+    fn a_dumb_terminal_gets_neither_unless_colorterm_says_so() {
+        let caps = detect(&[("TERM", "dumb")], true);
+        assert!(!caps.color);
+        assert!(!caps.emoji);
+
+        let caps = detect(&[("TERM", "dumb"), ("COLORTERM", "truecolor")], true);
+        assert!(caps.color);
+        assert!(!caps.emoji);
+    }
+
+    #[test]
+    fn the_linux_console_gets_color_but_no_emoji() {
+        let caps = detect(&[("TERM", "linux")], true);
+        assert!(caps.color);
+        assert!(!caps.emoji);
+    }
+
+    #[test]
+    fn a_pipe_gets_plain_text() {
+        let caps = detect(
+            &[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")],
+            false,
+        );
+        assert!(!caps.color);
+        assert!(!caps.emoji);
+    }
+
+    #[test]
+    fn flags_override_the_detection() {
+        let caps = detect(&[("TERM", "xterm-256color")], true).with_overrides(true, true);
+        assert!(!caps.color);
+        assert!(!caps.emoji);
+    }
+}
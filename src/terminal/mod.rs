@@ -0,0 +1,4 @@
+pub mod boxes;
+pub mod capabilities;
+pub mod prompt;
+pub mod screen;
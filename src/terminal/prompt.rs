@@ -0,0 +1,47 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+
+/// Reads one line from stdin without its line ending (a Windows `\r` included). Returns
+/// `None` when there is no answer to be had because stdin is closed or unreadable; invalid
+/// UTF-8 is replaced rather than rejected.
+pub fn read_line() -> Option<String> {
+    let mut bytes = Vec::new();
+    match io::stdin().lock().read_until(b'\n', &mut bytes) {
+        Ok(0) => None,
+        Ok(_) => Some(
+            String::from_utf8_lossy(&bytes)
+                .trim_end_matches(['\r', '\n'])
+                .to_string(),
+        ),
+        Err(err) => {
+            eprintln!("Couldn't read from stdin ({}).", err);
+            None
+        }
+    }
+}
+
+/// Asks a yes/no question that defaults to no. Works with piped answers too; if stdin runs
+/// out before one arrives, the default is taken and said so.
+pub fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    let _ = io::stdout().flush();
+
+    match read_line() {
+        Some(answer) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+        None => {
+            println!();
+            println!("(no answer, assuming no)");
+            false
+        }
+    }
+}
+
+/// Waits for Enter, but only when someone is at the keyboard to press it; piped input is
+/// left alone for whatever reads it next.
+pub fn pause(message: &str) {
+    if !io::stdin().is_terminal() {
+        return;
+    }
+
+    println!("{}", message);
+    let _ = read_line();
+}
@@ -0,0 +1,45 @@
+use std::{io, panic, thread};
+
+use crossterm::{
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+
+/// Raw mode and the alternate screen for as long as this is alive. Dropping it, whether on a
+/// normal return, an error or a panic, gives the shell its terminal back.
+pub struct RawScreen(());
+
+impl RawScreen {
+    /// Switches to raw mode and the alternate screen. Fails without changing anything if the
+    /// terminal refuses raw mode.
+    pub fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        let screen = RawScreen(());
+        execute!(io::stdout(), EnterAlternateScreen)?;
+
+        // The panic message is printed before unwinding reaches `drop`, so restore the
+        // terminal first or it lands on the alternate screen and is lost.
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            restore();
+            default_hook(info);
+        }));
+
+        Ok(screen)
+    }
+}
+
+impl Drop for RawScreen {
+    fn drop(&mut self) {
+        // Back to the standard hook; swapping hooks isn't allowed mid-panic.
+        if !thread::panicking() {
+            let _ = panic::take_hook();
+        }
+        restore();
+    }
+}
+
+fn restore() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen);
+}
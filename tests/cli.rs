@@ -0,0 +1,412 @@
+//! Runs the built binary against a throwaway data directory.
+
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Child, Command, Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use mons_box_cli::app_state::{config::Config, monster::Monster, sprite::Skin};
+
+/// A scratch home for one test, so no run ever touches the real save.
+struct Sandbox {
+    dir: PathBuf,
+}
+
+impl Sandbox {
+    fn new(test: &str) -> Self {
+        let dir = env::temp_dir().join(format!("mons-box-cli-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        Sandbox { dir }
+    }
+
+    fn state_file(&self) -> PathBuf {
+        self.dir.join("monster.json")
+    }
+
+    /// The binary with `args`, saving to `state_file` and reading nothing from stdin.
+    fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_mons-box-cli"));
+        command
+            .args(args)
+            .current_dir(&self.dir)
+            .env("HOME", &self.dir)
+            .env("XDG_DATA_HOME", self.dir.join("data"))
+            .env("MONS_BOX_STATE", self.state_file())
+            .env_remove("MONS_BOX_CONFIG")
+            .env_remove("MONS_BOX_STATS")
+            .stdin(Stdio::null());
+        command
+    }
+
+    fn run(&self, args: &[&str]) -> Output {
+        self.command(args).output().unwrap()
+    }
+
+    /// Like `run`, but with `input` piped to stdin.
+    fn run_with_input(&self, args: &[&str], input: &str) -> Output {
+        let mut child = self
+            .command(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        finish_within_timeout(child)
+    }
+
+    /// Every file left behind in the sandbox, relative to it.
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        collect_files(&self.dir, &self.dir, &mut files);
+        files
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(root, &path, files);
+        } else {
+            files.push(path.strip_prefix(root).unwrap().to_path_buf());
+        }
+    }
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn status_without_a_monster_creates_nothing() {
+    let sandbox = Sandbox::new("no-monster");
+    let output = sandbox.run(&["status"]);
+
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("No monster yet"));
+    assert_eq!(sandbox.files(), Vec::<PathBuf>::new());
+}
+
+fn read_state(sandbox: &Sandbox) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(sandbox.state_file()).unwrap()).unwrap()
+}
+
+#[test]
+fn undo_puts_back_the_stats_from_before_feeding() {
+    let sandbox = Sandbox::new("undo");
+    assert!(sandbox.run(&["--name", "A", "play"]).status.success());
+    let before = read_state(&sandbox);
+
+    assert!(sandbox.run(&["feed"]).status.success());
+    assert_ne!(read_state(&sandbox)["hunger"], before["hunger"]);
+
+    let output = sandbox.run(&["undo"]);
+    assert!(stdout(&output).contains("Undid the last action"));
+    let after = read_state(&sandbox);
+    for stat in ["hunger", "happiness", "energy", "health", "weight", "age"] {
+        assert_eq!(after[stat], before[stat], "{stat} wasn't restored");
+    }
+
+    assert!(stdout(&sandbox.run(&["undo"])).contains("Nothing to undo"));
+}
+
+/// Waits for `child` to exit, killing it and failing the test if it takes longer than a few
+/// seconds.
+fn finish_within_timeout(mut child: Child) -> Output {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while child.try_wait().unwrap().is_none() {
+        if Instant::now() > deadline {
+            child.kill().unwrap();
+            panic!("still running; it's waiting on stdin");
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn no_intro_goes_straight_to_the_commands() {
+    let sandbox = Sandbox::new("no-intro");
+    let mut child = sandbox
+        .command(&["--name", "A", "--no-intro", "interactive", "--no-autosave"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Stdin stays open, so only the quit command can end the session.
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(b"quit\n").unwrap();
+
+    let output = finish_within_timeout(child);
+    drop(stdin);
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(!stdout.contains("Welcome to CLI Mons Box"));
+    assert!(!stdout.contains("Press Enter"));
+    assert!(stdout.contains("Thanks for playing"));
+}
+
+#[test]
+fn the_banner_comes_from_the_config() {
+    let sandbox = Sandbox::new("banner");
+    let config = sandbox.dir.join("config.json");
+    fs::write(&config, r#"{"banner": "Hello from the box"}"#).unwrap();
+
+    let output = sandbox.run(&[
+        "--config",
+        config.to_str().unwrap(),
+        "--name",
+        "A",
+        "interactive",
+        "--no-autosave",
+    ]);
+
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("Hello from the box"));
+    assert!(!stdout.contains("Welcome to CLI Mons Box"));
+}
+
+#[test]
+fn compare_names_the_missing_side() {
+    let sandbox = Sandbox::new("compare-missing");
+    assert!(sandbox.run(&["--name", "A", "play"]).status.success());
+    let missing = sandbox.dir.join("nope.json");
+
+    let output = sandbox.run(&[
+        "compare",
+        sandbox.state_file().to_str().unwrap(),
+        missing.to_str().unwrap(),
+    ]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No monster found at"));
+}
+
+#[test]
+fn ephemeral_status_writes_nothing() {
+    let sandbox = Sandbox::new("ephemeral");
+    let output = sandbox.run(&["--ephemeral", "--stats", r#"{"happiness": 95}"#, "status"]);
+
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("Feeling: Fluffy is"));
+    assert_eq!(sandbox.files(), Vec::<PathBuf>::new());
+}
+
+#[test]
+fn ephemeral_actions_write_nothing() {
+    let sandbox = Sandbox::new("ephemeral-feed");
+    let output = sandbox.run(&["--ephemeral", "feed"]);
+
+    assert!(output.status.success());
+    assert_eq!(sandbox.files(), Vec::<PathBuf>::new());
+}
+
+#[test]
+fn min_health_exit_trips_just_below_the_limit() {
+    let sandbox = Sandbox::new("min-health");
+    let sleep_with_limit = |limit: &str| {
+        sandbox
+            .run(&[
+                "--ephemeral",
+                "--stats",
+                r#"{"health": 50}"#,
+                "--min-health-exit",
+                limit,
+                "sleep",
+            ])
+            .status
+            .code()
+    };
+
+    assert_eq!(sleep_with_limit("50"), Some(0));
+    assert_eq!(sleep_with_limit("51"), Some(3));
+}
+
+#[test]
+fn a_truncated_save_falls_back_to_the_last_good_state() {
+    let sandbox = Sandbox::new("truncated");
+    assert!(sandbox.run(&["--name", "A", "play"]).status.success());
+    // Leaves an undo snapshot to fall back on.
+    assert!(sandbox.run(&["feed"]).status.success());
+    let saved = fs::read_to_string(sandbox.state_file()).unwrap();
+    fs::write(sandbox.state_file(), &saved[..saved.len() / 2]).unwrap();
+
+    let output = sandbox.run(&["status"]);
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(stdout(&output).contains("Feeling: A is"));
+    assert_eq!(read_state(&sandbox)["name"], "A");
+    assert!(sandbox.files().contains(&PathBuf::from("monster.json.bak")));
+}
+
+#[test]
+fn the_same_seed_hatches_and_feeds_the_same_monster() {
+    let run = |test| {
+        let sandbox = Sandbox::new(test);
+        let output = sandbox.run(&["--seed", "42", "--species", "random", "feed"]);
+        assert!(output.status.success());
+
+        let mut state = read_state(&sandbox);
+        let state = state.as_object_mut().unwrap();
+        for timestamp in [
+            "updated_at",
+            "last_cared_at",
+            "last_fed_at",
+            "last_care_day",
+        ] {
+            state.remove(timestamp);
+        }
+        (stdout(&output), state.clone())
+    };
+
+    assert_eq!(run("seed-a"), run("seed-b"));
+}
+
+const DAMAGED_SAVE: &str = "{\"name\": \"Testy\", \"hun";
+
+#[test]
+fn a_damaged_save_without_backups_starts_over() {
+    let sandbox = Sandbox::new("damaged");
+    fs::write(sandbox.state_file(), DAMAGED_SAVE).unwrap();
+
+    let output = sandbox.run(&["--name", "B", "feed"]);
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Starting over with a new monster"));
+    assert_eq!(read_state(&sandbox)["name"], "B");
+    let kept = sandbox.dir.join("monster.json.bak");
+    assert_eq!(fs::read_to_string(kept).unwrap(), DAMAGED_SAVE);
+}
+
+#[test]
+fn strict_refuses_to_start_over_from_a_damaged_save() {
+    let sandbox = Sandbox::new("damaged-strict");
+    fs::write(sandbox.state_file(), DAMAGED_SAVE).unwrap();
+
+    let output = sandbox.run(&["--strict", "--name", "B", "feed"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        fs::read_to_string(sandbox.state_file()).unwrap(),
+        DAMAGED_SAVE
+    );
+    assert_eq!(sandbox.files(), [PathBuf::from("monster.json")]);
+}
+
+#[test]
+fn prompt_leaves_a_damaged_save_alone() {
+    let sandbox = Sandbox::new("prompt-damaged");
+    fs::write(sandbox.state_file(), DAMAGED_SAVE).unwrap();
+
+    let output = sandbox.run(&["prompt"]);
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+    assert_eq!(
+        fs::read(sandbox.state_file()).unwrap(),
+        DAMAGED_SAVE.as_bytes()
+    );
+    assert_eq!(sandbox.files(), [PathBuf::from("monster.json")]);
+}
+
+/// A sandbox whose save holds a monster that has died.
+fn dead_monster(test: &str) -> Sandbox {
+    let sandbox = Sandbox::new(test);
+    assert!(sandbox.run(&["--name", "Gone", "play"]).status.success());
+    let mut state = read_state(&sandbox);
+    state["is_alive"] = false.into();
+    state["health"] = 0.into();
+    fs::write(sandbox.state_file(), state.to_string()).unwrap();
+    sandbox
+}
+
+#[test]
+fn stats_only_exits_like_plain_status() {
+    let dead = dead_monster("exit-codes");
+    for stats in [r#"{"happiness": 10}"#, r#"{"hunger": 90}"#] {
+        let plain = dead.run(&["--ephemeral", "--stats", stats, "status"]);
+        let stats_only = dead.run(&["--ephemeral", "--stats", stats, "status", "--stats-only"]);
+        assert_eq!(plain.status.code(), stats_only.status.code(), "{stats}");
+    }
+    assert_eq!(dead.run(&["status"]).status.code(), Some(1));
+    assert_eq!(dead.run(&["status", "--stats-only"]).status.code(), Some(1));
+
+    let sad = dead.run(&[
+        "--ephemeral",
+        "--stats",
+        r#"{"happiness": 10}"#,
+        "status",
+        "--stats-only",
+    ]);
+    assert_eq!(sad.status.code(), Some(0));
+    assert!(stdout(&sad).contains("band=warning"));
+}
+
+#[test]
+fn a_reset_without_autosave_waits_for_a_save() {
+    let sandbox = dead_monster("reset-discarded");
+    let before = sandbox.files();
+
+    let output = sandbox.run_with_input(
+        &["--no-intro", "interactive", "--no-autosave"],
+        "reset\nquit\nquit\n",
+    );
+
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("Game has been reset"));
+    assert!(stdout(&output).contains("Changes since your last save were discarded"));
+    let state = read_state(&sandbox);
+    assert_eq!(state["is_alive"], false);
+    assert_eq!(state["name"], "Gone");
+    assert_eq!(sandbox.files(), before, "the old save was backed up");
+}
+
+#[test]
+fn a_reset_without_autosave_is_written_on_save() {
+    let sandbox = dead_monster("reset-saved");
+
+    let output = sandbox.run_with_input(
+        &["--no-intro", "interactive", "--no-autosave"],
+        "reset\nw\nquit\n",
+    );
+
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("backed up"));
+    let state = read_state(&sandbox);
+    assert_eq!(state["is_alive"], true);
+    assert_ne!(state["name"], "Gone");
+}
+
+#[test]
+fn a_new_skin_survives_quitting() {
+    let sandbox = Sandbox::new("skin");
+    assert!(sandbox.run(&["--name", "A", "play"]).status.success());
+
+    let output = sandbox.run_with_input(&["--no-intro", "interactive"], "skin\nquit\n");
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("wearing the Round skin"));
+
+    let reloaded = Monster::load_from(&sandbox.state_file(), &Config::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(reloaded.skin, Skin::Round);
+}